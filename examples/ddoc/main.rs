@@ -230,13 +230,8 @@ fn generate_docs_directory(
   let options = deno_doc::html::GenerateOptions {
     package_name,
     main_entrypoint,
-    href_resolver: Rc::new(EmptyResolver()),
-    usage_composer: None,
     rewrite_map: Some(index_map),
-    category_docs: None,
-    disable_search: false,
-    symbol_redirect_map: None,
-    default_symbol_map: None,
+    ..deno_doc::html::GenerateOptions::new(Rc::new(EmptyResolver()))
   };
   let html = deno_doc::html::generate(options, doc_nodes_by_url)?;
 
@@ -242,6 +242,7 @@ export type JsDocTagKind =
   | "extends"
   | "ignore"
   | "module"
+  | "narrows"
   | "param"
   | "public"
   | "private"
@@ -255,6 +256,7 @@ export type JsDocTagKind =
   | "typedef"
   | "type"
   | "see"
+  | "yields"
   | "unsupported";
 
 export type JsDocTag =
@@ -265,9 +267,11 @@ export type JsDocTag =
   | JsDocTagValued
   | JsDocTagTyped
   | JsDocTagNamedTyped
+  | JsDocTagNarrows
   | JsDocTagParam
   | JsDocTagReturn
   | JsDocTagTags
+  | JsDocTagYields
   | JsDocTagUnsupported;
 
 export interface JsDocTagBase {
@@ -329,6 +333,13 @@ export interface JsDocTagParam extends JsDocTagBase {
   doc?: string;
 }
 
+export interface JsDocTagNarrows extends JsDocTagBase {
+  kind: "narrows";
+  name: string;
+  type: string;
+  doc?: string;
+}
+
 export interface JsDocTagReturn extends JsDocTagBase {
   kind: "return";
   type?: string;
@@ -340,6 +351,12 @@ export interface JsDocTagTags extends JsDocTagBase {
   tags: string[];
 }
 
+export interface JsDocTagYields extends JsDocTagBase {
+  kind: "yields";
+  type?: string;
+  doc?: string;
+}
+
 export interface JsDocTagUnsupported extends JsDocTagBase {
   kind: "unsupported";
   value: string;
@@ -467,6 +484,7 @@ export interface ParamArrayDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  typeComment?: string;
 }
 
 export interface ParamAssignDef {
@@ -475,6 +493,7 @@ export interface ParamAssignDef {
   right: string;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  typeComment?: string;
 }
 
 export interface ParamIdentifierDef {
@@ -483,6 +502,7 @@ export interface ParamIdentifierDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  typeComment?: string;
 }
 
 export interface ParamObjectDef {
@@ -491,6 +511,7 @@ export interface ParamObjectDef {
   optional: boolean;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  typeComment?: string;
 }
 
 export interface ParamRestDef {
@@ -498,6 +519,7 @@ export interface ParamRestDef {
   arg: ParamDef;
   decorators?: DecoratorDef[];
   tsType?: TsTypeDef;
+  typeComment?: string;
 }
 
 export type TruePlusMinus = true | "+" | "-";
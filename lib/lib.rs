@@ -166,7 +166,7 @@ async fn inner_doc(
   print_import_map_diagnostics: bool,
 ) -> Result<JsValue, anyhow::Error> {
   let root_specifier = ModuleSpecifier::parse(&root_specifier)?;
-  let mut loader = JsLoader::new(load);
+  let loader = JsLoader::new(load);
   let maybe_resolver: Option<Box<dyn Resolver>> = if let Some(import_map) =
     maybe_import_map
   {
@@ -222,7 +222,7 @@ async fn inner_doc(
   graph
     .build(
       vec![root_specifier.clone()],
-      &mut loader,
+      &loader,
       BuildOptions {
         module_analyzer: &analyzer,
         resolver: maybe_resolver.as_ref().map(|r| r.as_ref()),
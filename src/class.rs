@@ -395,6 +395,43 @@ pub fn class_to_class_def(
           properties.push(prop_def);
         }
       }
+      AutoAccessor(auto_accessor) => {
+        let deno_ast::swc::ast::Key::Public(key) = &auto_accessor.key else {
+          continue;
+        };
+
+        if let Some(prop_js_doc) =
+          js_doc_for_range(parsed_source, &auto_accessor.range())
+        {
+          let ts_type = if let Some(type_ann) = &auto_accessor.type_ann {
+            Some(TsTypeDef::new(parsed_source, &type_ann.type_ann))
+          } else if let Some(value) = &auto_accessor.value {
+            infer_ts_type_from_expr(parsed_source, value, false)
+          } else {
+            None
+          };
+
+          let prop_name = prop_name_to_string(parsed_source, key);
+
+          let decorators =
+            decorators_to_defs(parsed_source, &auto_accessor.decorators);
+
+          let prop_def = ClassPropertyDef {
+            js_doc: prop_js_doc,
+            ts_type,
+            readonly: false,
+            optional: false,
+            is_abstract: auto_accessor.is_abstract,
+            is_static: auto_accessor.is_static,
+            is_override: auto_accessor.is_override,
+            accessibility: auto_accessor.accessibility,
+            name: prop_name.into_boxed_str(),
+            decorators,
+            location: get_location(parsed_source, auto_accessor.start()),
+          };
+          properties.push(prop_def);
+        }
+      }
       TsIndexSignature(ts_index_sig) => {
         if let Some(js_doc) =
           js_doc_for_range(parsed_source, &ts_index_sig.range())
@@ -1,9 +1,13 @@
 // Copyright 2020-2023 the Deno authors. All rights reserved. MIT license.
 
+use crate::function::FunctionDef;
 use crate::js_doc::JsDoc;
+use crate::js_doc::JsDocTag;
 use crate::node::DeclarationKind;
 use crate::node::DocNode;
 use crate::node::NamespaceDef;
+use crate::params::ParamDef;
+use crate::params::ParamPatternDef;
 use crate::ts_type::TsTypeDef;
 use crate::util::swc::get_text_info_location;
 use crate::util::swc::has_ignorable_js_doc_tag;
@@ -28,8 +32,10 @@ use deno_graph::symbols::ModuleInfoRef;
 use deno_graph::symbols::RootSymbol;
 use deno_graph::symbols::Symbol;
 use deno_graph::symbols::UniqueSymbolId;
+use indexmap::IndexMap;
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::rc::Rc;
 
@@ -39,6 +45,9 @@ pub enum DocDiagnosticKind {
   MissingExplicitType,
   MissingReturnType,
   PrivateTypeRef(Box<PrivateTypeRefDiagnostic>),
+  DuplicateOverload(Box<DuplicateOverloadDiagnostic>),
+  OverloadParamMismatch(Box<OverloadParamMismatchDiagnostic>),
+  OptionalRestParam(Box<OptionalRestParamDiagnostic>),
 }
 
 #[derive(Debug, Clone)]
@@ -50,6 +59,43 @@ pub struct PrivateTypeRefDiagnostic {
   pub reference_location: Location,
 }
 
+#[derive(Debug, Clone)]
+pub struct DuplicateOverloadDiagnostic {
+  /// The name of the function.
+  pub name: String,
+  /// The 1-based index of the redundant overload.
+  pub index: usize,
+  /// The 1-based index of the overload which makes it redundant.
+  pub covered_by: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct OverloadParamMismatchDiagnostic {
+  /// The name of the function.
+  pub name: String,
+  /// The 1-based index of the overload.
+  pub index: usize,
+  pub mismatch: ParamCountMismatch,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParamCountMismatch {
+  /// The overload declares more parameters than the implementation accepts.
+  TooMany { declared: usize, accepted: usize },
+  /// The overload can be called with fewer arguments than the implementation
+  /// requires.
+  TooFew {
+    required: usize,
+    implementation_required: usize,
+  },
+}
+
+#[derive(Debug, Clone)]
+pub struct OptionalRestParamDiagnostic {
+  /// The name of the rest parameter.
+  pub name: String,
+}
+
 #[derive(Clone)]
 pub struct DocDiagnostic {
   pub location: Location,
@@ -79,6 +125,11 @@ impl Diagnostic for DocDiagnostic {
       DocDiagnosticKind::MissingExplicitType => "missing-explicit-type",
       DocDiagnosticKind::MissingReturnType => "missing-return-type",
       DocDiagnosticKind::PrivateTypeRef { .. } => "private-type-ref",
+      DocDiagnosticKind::DuplicateOverload { .. } => "duplicate-overload",
+      DocDiagnosticKind::OverloadParamMismatch { .. } => {
+        "overload-param-mismatch"
+      }
+      DocDiagnosticKind::OptionalRestParam { .. } => "optional-rest-param",
     })
   }
 
@@ -97,6 +148,29 @@ impl Diagnostic for DocDiagnostic {
         "public type '{}' references private type '{}'",
         diagnostic.name, diagnostic.reference,
       )),
+      DocDiagnosticKind::DuplicateOverload(diagnostic) => Cow::Owned(format!(
+        "overload {} of function '{}' is redundant with overload {}",
+        diagnostic.index, diagnostic.name, diagnostic.covered_by,
+      )),
+      DocDiagnosticKind::OverloadParamMismatch(diagnostic) => {
+        Cow::Owned(match diagnostic.mismatch {
+          ParamCountMismatch::TooMany { declared, accepted } => format!(
+            "overload {} of function '{}' declares {declared} parameters, but the implementation accepts at most {accepted}",
+            diagnostic.index, diagnostic.name,
+          ),
+          ParamCountMismatch::TooFew {
+            required,
+            implementation_required,
+          } => format!(
+            "overload {} of function '{}' can be called with {required} arguments, but the implementation requires {implementation_required}",
+            diagnostic.index, diagnostic.name,
+          ),
+        })
+      }
+      DocDiagnosticKind::OptionalRestParam(diagnostic) => Cow::Owned(format!(
+        "rest parameter '{}' is marked as optional in its JSDoc",
+        diagnostic.name,
+      )),
     }
   }
 
@@ -136,6 +210,15 @@ impl Diagnostic for DocDiagnostic {
       DocDiagnosticKind::PrivateTypeRef { .. } => Some(Cow::Borrowed(
         "make the referenced type public or remove the reference",
       )),
+      DocDiagnosticKind::DuplicateOverload { .. } => {
+        Some(Cow::Borrowed("remove the redundant overload"))
+      }
+      DocDiagnosticKind::OverloadParamMismatch { .. } => Some(Cow::Borrowed(
+        "update the overload or the implementation signature so that the parameters match",
+      )),
+      DocDiagnosticKind::OptionalRestParam { .. } => Some(Cow::Borrowed(
+        "remove the brackets around the name in the @param tag",
+      )),
       _ => None,
     }
   }
@@ -172,6 +255,21 @@ impl Diagnostic for DocDiagnostic {
           "to ensure documentation is complete all types that are exposed in the public API must be public",
         )])
       }
+      DocDiagnosticKind::DuplicateOverload { .. } => {
+        Cow::Borrowed(&[Cow::Borrowed(
+          "every call matched by this overload is already matched by another overload with the same return type",
+        )])
+      }
+      DocDiagnosticKind::OverloadParamMismatch { .. } => {
+        Cow::Borrowed(&[Cow::Borrowed(
+          "calls matching this overload can not be handled by the implementation signature",
+        )])
+      }
+      DocDiagnosticKind::OptionalRestParam { .. } => {
+        Cow::Borrowed(&[Cow::Borrowed(
+          "rest parameters can always be omitted, so they can not be marked as optional",
+        )])
+      }
     }
   }
 
@@ -330,6 +428,129 @@ impl<'a> DiagnosticsCollector<'a> {
     }
   }
 
+  fn check_optional_rest_params(
+    &mut self,
+    params: &[ParamDef],
+    js_doc: &JsDoc,
+    location: &Location,
+  ) {
+    if has_ignorable_js_doc_tag(js_doc) {
+      return;
+    }
+
+    for param in params {
+      let ParamPatternDef::Rest { arg } = &param.pattern else {
+        continue;
+      };
+      let ParamPatternDef::Identifier { name, .. } = &arg.pattern else {
+        continue;
+      };
+
+      let optional = js_doc.tags.iter().any(|tag| {
+        matches!(
+          tag,
+          JsDocTag::Param { name: tag_name, optional: true, .. }
+            if **tag_name == **name
+        )
+      });
+
+      if optional {
+        if let Some(text_info) = self.maybe_get_text_info(location) {
+          self.diagnostics.push(DocDiagnostic {
+            location: location.clone(),
+            kind: DocDiagnosticKind::OptionalRestParam(Box::new(
+              OptionalRestParamDiagnostic { name: name.clone() },
+            )),
+            text_info,
+          });
+        }
+      }
+    }
+  }
+
+  fn check_duplicate_overloads(&mut self, name: &str, overloads: &[&DocNode]) {
+    let function_defs = overloads
+      .iter()
+      .map(|doc_node| doc_node.function_def().unwrap())
+      .collect::<Vec<_>>();
+
+    for (i, doc_node) in overloads.iter().enumerate() {
+      if has_ignorable_js_doc_tag(&doc_node.js_doc) {
+        continue;
+      }
+
+      let covered_by =
+        function_defs.iter().enumerate().position(|(j, other)| {
+          i != j
+            && overload_covers(other, function_defs[i])
+            && (j < i || other.params.len() != function_defs[i].params.len())
+        });
+
+      if let Some(covered_by) = covered_by {
+        if let Some(text_info) = self.maybe_get_text_info(&doc_node.location) {
+          self.diagnostics.push(DocDiagnostic {
+            location: doc_node.location.clone(),
+            kind: DocDiagnosticKind::DuplicateOverload(Box::new(
+              DuplicateOverloadDiagnostic {
+                name: name.to_string(),
+                index: i + 1,
+                covered_by: covered_by + 1,
+              },
+            )),
+            text_info,
+          });
+        }
+      }
+    }
+  }
+
+  fn check_overload_params(
+    &mut self,
+    name: &str,
+    overloads: &[&DocNode],
+    implementation: &DocNode,
+  ) {
+    let implementation_params = &implementation.function_def().unwrap().params;
+    let accepted = (!implementation_params.iter().any(is_rest))
+      .then_some(implementation_params.len());
+    let implementation_required = required_params(implementation_params);
+
+    for (i, doc_node) in overloads.iter().enumerate() {
+      if has_ignorable_js_doc_tag(&doc_node.js_doc) {
+        continue;
+      }
+
+      let params = &doc_node.function_def().unwrap().params;
+      let declared = params.iter().filter(|param| !is_rest(param)).count();
+      let required = required_params(params);
+
+      let mismatch = match accepted {
+        Some(accepted) if declared > accepted => {
+          ParamCountMismatch::TooMany { declared, accepted }
+        }
+        _ if required < implementation_required => ParamCountMismatch::TooFew {
+          required,
+          implementation_required,
+        },
+        _ => continue,
+      };
+
+      if let Some(text_info) = self.maybe_get_text_info(&doc_node.location) {
+        self.diagnostics.push(DocDiagnostic {
+          location: doc_node.location.clone(),
+          kind: DocDiagnosticKind::OverloadParamMismatch(Box::new(
+            OverloadParamMismatchDiagnostic {
+              name: name.to_string(),
+              index: i + 1,
+              mismatch,
+            },
+          )),
+          text_info,
+        });
+      }
+    }
+  }
+
   fn maybe_get_text_info(&self, location: &Location) -> Option<SourceTextInfo> {
     fn try_get(
       root_symbol: &RootSymbol,
@@ -368,6 +589,9 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
   where
     I: Iterator<Item = &'c DocNode>,
   {
+    let doc_nodes = doc_nodes.collect::<Vec<_>>();
+    self.visit_overloads(&doc_nodes);
+
     let mut last_node: Option<&DocNode> = None;
     for doc_node in doc_nodes {
       if !doc_node.location.filename.starts_with("file:") {
@@ -393,6 +617,43 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
     }
   }
 
+  fn visit_overloads(&mut self, doc_nodes: &[&DocNode]) {
+    let mut overloads_by_name = IndexMap::<&str, Vec<&DocNode>>::new();
+    let mut implementations = HashMap::<&str, &DocNode>::new();
+    for doc_node in doc_nodes {
+      if !doc_node.location.filename.starts_with("file:")
+        || doc_node.declaration_kind == DeclarationKind::Private
+      {
+        continue;
+      }
+
+      if let Some(function_def) = doc_node.function_def() {
+        if function_def.has_body {
+          implementations.entry(&doc_node.name).or_insert(doc_node);
+        } else {
+          overloads_by_name
+            .entry(&doc_node.name)
+            .or_default()
+            .push(doc_node);
+        }
+      }
+    }
+
+    for (name, overloads) in overloads_by_name {
+      if overloads.len() > 1 {
+        self.diagnostics.check_duplicate_overloads(name, &overloads);
+      }
+
+      if let Some(implementation) = implementations.get(name) {
+        self.diagnostics.check_overload_params(
+          name,
+          &overloads,
+          implementation,
+        );
+      }
+    }
+  }
+
   fn visit_doc_node(&mut self, doc_node: &DocNode) {
     fn is_js_docable_kind(kind: &DocNodeKind) -> bool {
       match kind {
@@ -525,6 +786,11 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
       &parent.js_doc,
       &parent.location,
     );
+    self.diagnostics.check_optional_rest_params(
+      &def.params,
+      &parent.js_doc,
+      &parent.location,
+    );
   }
 
   fn visit_interface_def(&mut self, def: &crate::interface::InterfaceDef) {
@@ -590,3 +856,48 @@ impl<'a, 'b> DiagnosticDocNodeVisitor<'a, 'b> {
     );
   }
 }
+
+/// Whether every call matched by the `covered` overload is also matched by
+/// the `by` overload, ie. they only differ in parameter names or `by` accepts
+/// additional optional parameters.
+fn overload_covers(by: &FunctionDef, covered: &FunctionDef) -> bool {
+  fn param_type(param: &ParamDef) -> Option<&TsTypeDef> {
+    match &param.pattern {
+      ParamPatternDef::Assign { left, .. } => {
+        left.ts_type.as_ref().or(param.ts_type.as_ref())
+      }
+      _ => param.ts_type.as_ref(),
+    }
+  }
+
+  by.type_params == covered.type_params
+    && by.return_type == covered.return_type
+    && by.params.len() >= covered.params.len()
+    && by.params.iter().zip(covered.params.iter()).all(|(a, b)| {
+      param_type(a) == param_type(b)
+        && is_rest(a) == is_rest(b)
+        && is_optional(a) == is_optional(b)
+    })
+    && by.params[covered.params.len()..].iter().all(is_optional)
+}
+
+fn is_rest(param: &ParamDef) -> bool {
+  matches!(param.pattern, ParamPatternDef::Rest { .. })
+}
+
+fn is_optional(param: &ParamDef) -> bool {
+  match &param.pattern {
+    ParamPatternDef::Array { optional, .. }
+    | ParamPatternDef::Identifier { optional, .. }
+    | ParamPatternDef::Object { optional, .. } => *optional,
+    ParamPatternDef::Assign { .. } | ParamPatternDef::Rest { .. } => true,
+  }
+}
+
+/// The number of arguments a call has to pass at least.
+fn required_params(params: &[ParamDef]) -> usize {
+  params
+    .iter()
+    .rposition(|param| !is_optional(param))
+    .map_or(0, |i| i + 1)
+}
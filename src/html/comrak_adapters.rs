@@ -4,6 +4,7 @@ use comrak::adapters::HeadingAdapter;
 use comrak::adapters::HeadingMeta;
 use comrak::adapters::SyntaxHighlighterAdapter;
 use comrak::nodes::Sourcepos;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::io::Write;
@@ -15,6 +16,11 @@ pub struct HighlightAdapter {
   pub language_cb:
     fn(&str) -> Option<&'static tree_sitter_highlight::HighlightConfiguration>,
   pub show_line_numbers: bool,
+  pub copy_button: bool,
+  /// When set, line numbers starting at this line are shown in the gutter,
+  /// without the anchors of `show_line_numbers`, eg. for excerpts of source
+  /// in examples.
+  pub excerpt_start_line: Option<usize>,
 }
 
 impl HighlightAdapter {
@@ -33,7 +39,11 @@ impl HighlightAdapter {
     for (i, line) in iter.enumerate() {
       let n = i + 1;
 
-      if self.show_line_numbers {
+      if let Some(start) = self.excerpt_start_line {
+        line_numbers
+          .push_str(&format!(r#"<span class="block">{}</span>"#, start + i));
+        lines.push_str(r#"<span class="block">"#);
+      } else if self.show_line_numbers {
         line_numbers.push_str(&format!(
           r##"<a href="#L{n}" class="no_color block">{n}</a>"##,
         ));
@@ -45,12 +55,12 @@ impl HighlightAdapter {
 
       highlighter(&mut lines, line)?;
 
-      if self.show_line_numbers {
+      if self.has_gutter() {
         lines.push_str("</span>");
       }
     }
 
-    if self.show_line_numbers {
+    if self.has_gutter() {
       Ok(format!(
         r##"<div class="lineNumbers">{line_numbers}</div><div class="grow overflow-x-auto">{lines}</div>"##
       ))
@@ -59,22 +69,49 @@ impl HighlightAdapter {
     }
   }
 
+  fn has_gutter(&self) -> bool {
+    self.show_line_numbers || self.excerpt_start_line.is_some()
+  }
+
   fn write_button(
     &self,
     output: &mut dyn Write,
+    lang: &str,
     source: &str,
   ) -> std::io::Result<()> {
+    if !self.copy_button {
+      return Ok(());
+    }
+
     write!(output, "</code>")?;
     write!(
       output,
       r#"<button class="context_button" data-copy="{}">{}</button>"#,
-      html_escape::encode_double_quoted_attribute(source),
+      html_escape::encode_double_quoted_attribute(&strip_prompts(lang, source)),
       include_str!("./templates/icons/copy.svg")
     )?;
     write!(output, "<code>")
   }
 }
 
+/// Strips the leading `$ ` prompt of shell session lines, so that copying a
+/// code block only copies the commands.
+fn strip_prompts<'a>(lang: &str, source: &'a str) -> Cow<'a, str> {
+  if !matches!(lang, "sh" | "bash" | "shell" | "console" | "shellsession")
+    || !source.lines().any(|line| line.starts_with("$ "))
+  {
+    return Cow::Borrowed(source);
+  }
+
+  Cow::Owned(
+    source
+      .lines()
+      .map(|line| line.strip_prefix("$ ").unwrap_or(line))
+      .collect::<Vec<_>>()
+      .join("\n"),
+  )
+}
+
 impl SyntaxHighlighterAdapter for HighlightAdapter {
   fn write_highlighted(
     &self,
@@ -103,7 +140,7 @@ impl SyntaxHighlighterAdapter for HighlightAdapter {
                 .unwrap();
 
               output.write_all(html.as_bytes())?;
-              return self.write_button(output, code);
+              return self.write_button(output, lang, code);
             }
             Err(err) => {
               eprintln!("Error rendering code: {}", err);
@@ -116,7 +153,7 @@ impl SyntaxHighlighterAdapter for HighlightAdapter {
       }
     }
     comrak::html::escape(output, source)?;
-    self.write_button(output, code)
+    self.write_button(output, lang, code)
   }
 
   fn write_pre_tag(
@@ -136,7 +173,7 @@ impl SyntaxHighlighterAdapter for HighlightAdapter {
     output: &mut dyn Write,
     mut attributes: HashMap<String, String>,
   ) -> std::io::Result<()> {
-    if self.show_line_numbers {
+    if self.has_gutter() {
       attributes
         .entry("class".into())
         .or_default()
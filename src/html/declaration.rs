@@ -0,0 +1,432 @@
+use crate::display::SliceDisplayer;
+use crate::function::FunctionDef;
+use crate::node::DocNode;
+use crate::node::DocNodeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type_param::TsTypeParamDef;
+use deno_ast::swc::ast::Accessibility;
+use deno_ast::swc::ast::MethodKind;
+use deno_ast::swc::ast::VarDeclKind;
+use std::fmt::Write;
+
+const INDENT: &str = "  ";
+
+/// Synthesize a `.d.ts` style declaration for the passed nodes, eg. all
+/// overloads of a function.
+pub(crate) fn synthesize_declaration(doc_nodes: &[&DocNode]) -> String {
+  let mut out = String::new();
+
+  for doc_node in doc_nodes {
+    if let DocNodeDef::Function { function_def } = &doc_node.def {
+      if function_def.has_body && doc_nodes.len() > 1 {
+        continue;
+      }
+    }
+
+    write_node(&mut out, doc_node, 0, true);
+  }
+
+  out.trim_end().to_string()
+}
+
+fn write_node(out: &mut String, doc_node: &DocNode, depth: usize, top: bool) {
+  let indent = INDENT.repeat(depth);
+  let declare = if top { "export declare " } else { "export " };
+
+  match &doc_node.def {
+    DocNodeDef::Function { function_def } => {
+      let _ = writeln!(
+        out,
+        "{indent}{declare}function {}{};",
+        doc_node.name,
+        function_signature(function_def),
+      );
+    }
+    DocNodeDef::Variable { variable_def } => {
+      let kind = match variable_def.kind {
+        VarDeclKind::Const => "const",
+        VarDeclKind::Let => "let",
+        VarDeclKind::Var => "var",
+      };
+
+      let _ = writeln!(
+        out,
+        "{indent}{declare}{kind} {}{};",
+        doc_node.name,
+        variable_def
+          .ts_type
+          .as_ref()
+          .map(|ts_type| format!(": {ts_type}"))
+          .unwrap_or_default(),
+      );
+    }
+    DocNodeDef::Enum { enum_def } => {
+      let _ = writeln!(out, "{indent}{declare}enum {} {{", doc_node.name);
+
+      for member in &enum_def.members {
+        let init = member
+          .init
+          .as_ref()
+          .map(|init| format!(" = {init}"))
+          .unwrap_or_default();
+
+        let _ = writeln!(out, "{indent}{INDENT}{}{init},", member.name);
+      }
+
+      let _ = writeln!(out, "{indent}}}");
+    }
+    DocNodeDef::Class { class_def } => {
+      let r#abstract = if class_def.is_abstract {
+        "abstract "
+      } else {
+        ""
+      };
+
+      let extends = class_def
+        .extends
+        .as_ref()
+        .map(|extends| {
+          let type_args = if class_def.super_type_params.is_empty() {
+            String::new()
+          } else {
+            format!(
+              "<{}>",
+              SliceDisplayer::new(&class_def.super_type_params, ", ", false)
+            )
+          };
+
+          format!(" extends {extends}{type_args}")
+        })
+        .unwrap_or_default();
+
+      let implements = if class_def.implements.is_empty() {
+        String::new()
+      } else {
+        format!(
+          " implements {}",
+          SliceDisplayer::new(&class_def.implements, ", ", false)
+        )
+      };
+
+      let _ = writeln!(
+        out,
+        "{indent}{declare}{abstract}class {}{}{extends}{implements} {{",
+        doc_node.name,
+        type_params(&class_def.type_params),
+      );
+
+      for constructor in class_def.constructors.iter() {
+        if constructor.has_body && class_def.constructors.len() > 1 {
+          continue;
+        }
+
+        let params = constructor
+          .params
+          .iter()
+          .map(|param| {
+            format!(
+              "{}{}{}",
+              accessibility(param.accessibility),
+              if param.readonly { "readonly " } else { "" },
+              param.param
+            )
+          })
+          .collect::<Vec<_>>()
+          .join(", ");
+
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}{}constructor({params});",
+          accessibility(constructor.accessibility),
+        );
+      }
+
+      for index_signature in class_def.index_signatures.iter() {
+        let _ = writeln!(out, "{indent}{INDENT}{index_signature};");
+      }
+
+      for property in class_def.properties.iter() {
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}{}{}{}{}{}{}{};",
+          accessibility(property.accessibility),
+          if property.is_static { "static " } else { "" },
+          if property.is_abstract {
+            "abstract "
+          } else {
+            ""
+          },
+          if property.readonly { "readonly " } else { "" },
+          property.name,
+          if property.optional { "?" } else { "" },
+          property
+            .ts_type
+            .as_ref()
+            .map(|ts_type| format!(": {ts_type}"))
+            .unwrap_or_default(),
+        );
+      }
+
+      for method in class_def.methods.iter() {
+        if method.function_def.has_body
+          && class_def
+            .methods
+            .iter()
+            .filter(|other| {
+              other.name == method.name
+                && other.is_static == method.is_static
+                && other.kind == method.kind
+            })
+            .count()
+            > 1
+        {
+          continue;
+        }
+
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}{}{}{}{}{}{}{}({}){};",
+          accessibility(method.accessibility),
+          if method.is_static { "static " } else { "" },
+          if method.is_abstract { "abstract " } else { "" },
+          method_kind(method.kind),
+          method.name,
+          if method.optional { "?" } else { "" },
+          type_params(&method.function_def.type_params),
+          SliceDisplayer::new(&method.function_def.params, ", ", false),
+          method
+            .function_def
+            .return_type
+            .as_ref()
+            .filter(|_| method.kind != MethodKind::Setter)
+            .map(|ts_type| format!(": {ts_type}"))
+            .unwrap_or_default(),
+        );
+      }
+
+      let _ = writeln!(out, "{indent}}}");
+    }
+    DocNodeDef::TypeAlias { type_alias_def } => {
+      let _ = writeln!(
+        out,
+        "{indent}export type {}{} = {};",
+        doc_node.name,
+        type_params(&type_alias_def.type_params),
+        if type_alias_def.ts_type.kind == Some(TsTypeDefKind::Mapped) {
+          format!("{{ {} }}", type_alias_def.ts_type)
+        } else {
+          type_alias_def.ts_type.to_string()
+        },
+      );
+    }
+    DocNodeDef::Interface { interface_def } => {
+      let extends = if interface_def.extends.is_empty() {
+        String::new()
+      } else {
+        format!(
+          " extends {}",
+          SliceDisplayer::new(&interface_def.extends, ", ", false)
+        )
+      };
+
+      let _ = writeln!(
+        out,
+        "{indent}export interface {}{}{extends} {{",
+        doc_node.name,
+        type_params(&interface_def.type_params),
+      );
+
+      for constructor in &interface_def.constructors {
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}new {}({}){};",
+          type_params(&constructor.type_params),
+          SliceDisplayer::new(&constructor.params, ", ", false),
+          constructor
+            .return_type
+            .as_ref()
+            .map(|ts_type| format!(": {ts_type}"))
+            .unwrap_or_default(),
+        );
+      }
+
+      for call_signature in &interface_def.call_signatures {
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}{}{call_signature};",
+          type_params(&call_signature.type_params),
+        );
+      }
+
+      for index_signature in &interface_def.index_signatures {
+        let _ = writeln!(out, "{indent}{INDENT}{index_signature};");
+      }
+
+      for property in &interface_def.properties {
+        let _ = writeln!(out, "{indent}{INDENT}{property};");
+      }
+
+      for method in &interface_def.methods {
+        let _ = writeln!(
+          out,
+          "{indent}{INDENT}{}{}{}{}({}){};",
+          method_kind(method.kind),
+          if method.computed {
+            format!("[{}]", method.name)
+          } else {
+            method.name.clone()
+          },
+          if method.optional { "?" } else { "" },
+          type_params(&method.type_params),
+          SliceDisplayer::new(&method.params, ", ", false),
+          method
+            .return_type
+            .as_ref()
+            .filter(|_| method.kind != MethodKind::Setter)
+            .map(|ts_type| format!(": {ts_type}"))
+            .unwrap_or_default(),
+        );
+      }
+
+      let _ = writeln!(out, "{indent}}}");
+    }
+    DocNodeDef::Namespace { namespace_def } => {
+      let _ = writeln!(out, "{indent}{declare}namespace {} {{", doc_node.name);
+
+      for element in &namespace_def.elements {
+        write_node(out, element, depth + 1, false);
+      }
+
+      let _ = writeln!(out, "{indent}}}");
+    }
+    DocNodeDef::Import { .. } | DocNodeDef::ModuleDoc => {}
+  }
+}
+
+/// The plain text signature of a function, eg. `<T>(a: T): string`.
+pub(crate) fn function_signature(function_def: &FunctionDef) -> String {
+  format!(
+    "{}({}){}",
+    type_params(&function_def.type_params),
+    SliceDisplayer::new(&function_def.params, ", ", false),
+    function_def
+      .return_type
+      .as_ref()
+      .map(|ts_type| format!(": {ts_type}"))
+      .unwrap_or_default(),
+  )
+}
+
+fn type_params(type_params: &[TsTypeParamDef]) -> String {
+  if type_params.is_empty() {
+    String::new()
+  } else {
+    format!("<{}>", SliceDisplayer::new(type_params, ", ", false))
+  }
+}
+
+fn accessibility(accessibility: Option<Accessibility>) -> &'static str {
+  match accessibility {
+    Some(Accessibility::Protected) => "protected ",
+    Some(Accessibility::Private) => "private ",
+    Some(Accessibility::Public) | None => "",
+  }
+}
+
+fn method_kind(kind: MethodKind) -> &'static str {
+  match kind {
+    MethodKind::Method => "",
+    MethodKind::Getter => "get ",
+    MethodKind::Setter => "set ",
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::parser::DocParser;
+  use crate::tests::setup;
+  use crate::DocParserOptions;
+
+  async fn parse(source: &str) -> Vec<DocNode> {
+    let (graph, analyzer, specifier) =
+      setup("file:///mod.ts", vec![("file:///mod.ts", None, source)]).await;
+
+    DocParser::new(&graph, &analyzer, DocParserOptions::default())
+      .unwrap()
+      .parse(&specifier)
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn function_overloads() {
+    let nodes = parse(
+      r#"
+export function foo(a: string): string;
+export function foo<T>(a: T, b?: number): T;
+export function foo(a: unknown, b?: number): unknown {
+  return a;
+}
+"#,
+    )
+    .await;
+
+    assert_eq!(
+      synthesize_declaration(&nodes.iter().collect::<Vec<_>>()),
+      "export declare function foo(a: string): string;\nexport declare function foo<T>(a: T, b?: number): T;"
+    );
+  }
+
+  #[tokio::test]
+  async fn class_members() {
+    let nodes = parse(
+      r#"
+export abstract class Foo<T> extends Bar<T> implements Baz {
+  constructor(private readonly a: string) {}
+  static count?: number;
+  get value(): T { return undefined!; }
+  set value(v: T) {}
+  protected abstract run(x: number): void;
+}
+"#,
+    )
+    .await;
+
+    assert_eq!(
+      synthesize_declaration(&[&nodes[0]]),
+      r#"export declare abstract class Foo<T> extends Bar<T> implements Baz {
+  constructor(private readonly a: string);
+  static count?: number;
+  get value(): T;
+  set value(v: T);
+  protected abstract run(x: number): void;
+}"#
+    );
+  }
+
+  #[tokio::test]
+  async fn namespace_and_enum() {
+    let nodes = parse(
+      r#"
+export namespace ns {
+  export const a: number = 1;
+  export enum Color { Red = 1, Green }
+  export type Id = string;
+}
+"#,
+    )
+    .await;
+
+    assert_eq!(
+      synthesize_declaration(&[&nodes[0]]),
+      r#"export declare namespace ns {
+  export const a: number;
+  export enum Color {
+    Red = 1,
+    Green,
+  }
+  export type Id = string;
+}"#
+    );
+  }
+}
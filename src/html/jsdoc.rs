@@ -2,7 +2,9 @@ use super::render_context::RenderContext;
 use super::util::*;
 #[cfg(feature = "ammonia")]
 use crate::html::comrak_adapters::URLRewriter;
+use crate::html::ExampleStatus;
 use crate::html::ShortPath;
+use crate::html::SummaryLength;
 use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
 use crate::DocNodeKind;
@@ -11,6 +13,7 @@ use comrak::nodes::AstNode;
 use comrak::nodes::NodeHtmlBlock;
 use comrak::nodes::NodeValue;
 use comrak::Arena;
+use indexmap::IndexMap;
 use serde::Serialize;
 use std::borrow::Cow;
 use std::cell::RefCell;
@@ -26,6 +29,8 @@ lazy_static! {
     regex::Regex::new(r"(^\.{0,2}\/)|(^[A-Za-z]+:\S)").unwrap();
   static ref MODULE_LINK_RE: regex::Regex =
     regex::Regex::new(r"^\[(\S+)\](?:\.(\S+)|\s|)$").unwrap();
+  static ref EXTERNAL_LINK_RE: regex::Regex =
+    regex::Regex::new(r#"<a\s[^>]*href="(?:https?:)?//[^"]*"[^>]*>"#).unwrap();
 }
 
 #[cfg(feature = "ammonia")]
@@ -77,8 +82,12 @@ lazy_static! {
           "alert-important",
           "alert-warning",
           "alert-caution",
+          "lineNumbers",
+          "grow",
+          "overflow-x-auto",
         ],
       )
+      .add_allowed_classes("span", ["block"])
       .link_rel(Some("nofollow"))
       .url_relative(ammonia::UrlRelative::Custom(Box::new(
         AmmoniaRelativeUrlEvaluator(),
@@ -104,8 +113,9 @@ fn parse_links<'a>(md: &'a str, ctx: &RenderContext) -> Cow<'a, str> {
       == "code";
     let value = captures.name("value").unwrap().as_str();
 
-    let (link, mut title) = if let Some((link, title)) =
-      value.split_once('|').or_else(|| value.split_once(' '))
+    let (link, mut title) = if let Some((link, title)) = value
+      .split_once('|')
+      .or_else(|| value.trim().split_once(char::is_whitespace))
     {
       (link.trim(), title.trim().to_string())
     } else {
@@ -218,6 +228,33 @@ fn split_markdown_title(md: &str) -> (Option<&str>, Option<&str>) {
   }
 }
 
+/// Split a fenced `imports` code block, listing what is needed to run an
+/// example, off the body of the example.
+fn split_example_setup(body: &str) -> (Option<&str>, Cow<str>) {
+  const FENCE: &str = "```imports\n";
+
+  let Some(start) = body
+    .match_indices(FENCE)
+    .map(|(start, _)| start)
+    .find(|start| *start == 0 || body[..*start].ends_with('\n'))
+  else {
+    return (None, Cow::Borrowed(body));
+  };
+
+  let setup = &body[start + FENCE.len()..];
+  let Some(end) = setup.find("```") else {
+    return (None, Cow::Borrowed(body));
+  };
+
+  let rest = &setup[end + 3..];
+  let rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+
+  (
+    Some(setup[..end].trim_end()),
+    Cow::Owned(format!("{}{rest}", &body[..start])),
+  )
+}
+
 #[cfg(feature = "ammonia")]
 struct AmmoniaRelativeUrlEvaluator();
 
@@ -259,11 +296,14 @@ fn match_node_value<'a>(
         if paragraph_child.data.borrow().value == NodeValue::Paragraph {
           let alert = paragraph_child.first_child().and_then(|text_child| {
             if let NodeValue::Text(text) = &text_child.data.borrow().value {
-              match text
+              let (kind, title) = text
                 .split_once(' ')
                 .map_or((text.as_str(), None), |(kind, title)| {
                   (kind, Some(title))
-                }) {
+                });
+
+              // alert kinds are case-insensitive, like on GitHub
+              match (kind.to_ascii_uppercase().as_str(), title) {
                 ("[!NOTE]", title) => {
                   Some((Alert::Note, title.unwrap_or("Note").to_string()))
                 }
@@ -417,6 +457,84 @@ fn walk_node_title<'a>(node: &'a AstNode<'a>) {
   }
 }
 
+/// Truncate the text of a summary to the passed length, removing all nodes
+/// after the cut.
+fn truncate_summary<'a>(node: &'a AstNode<'a>, length: SummaryLength) {
+  let mut remaining = match length {
+    SummaryLength::Characters(n) | SummaryLength::Sentences(n) => n,
+  };
+  let mut truncated = false;
+
+  for node in node.descendants().collect::<Vec<_>>() {
+    if truncated {
+      node.detach();
+      continue;
+    }
+
+    let mut data = node.data.borrow_mut();
+    let literal = match &mut data.value {
+      NodeValue::Text(literal) => literal,
+      NodeValue::Code(code) => &mut code.literal,
+      _ => continue,
+    };
+
+    match length {
+      SummaryLength::Characters(_) => {
+        let count = literal.chars().count();
+
+        if count > remaining {
+          let cut = literal.chars().take(remaining).collect::<String>();
+          // avoid cutting in the middle of a word
+          let cut = if literal.chars().nth(remaining) == Some(' ') {
+            cut.as_str()
+          } else {
+            cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head)
+          };
+
+          let cut = cut.trim_end();
+          if cut.is_empty() {
+            // the ellipsis should not be formatted as code
+            data.value = NodeValue::Text("…".to_string());
+          } else {
+            *literal = format!("{cut}…");
+          }
+          truncated = true;
+        } else {
+          remaining -= count;
+        }
+      }
+      SummaryLength::Sentences(_) => {
+        let sentence_end = literal.char_indices().find_map(|(i, char)| {
+          let end = i + char.len_utf8();
+          // abbreviations, eg. `e.g.`, are followed by a lowercase word
+          let mut rest = literal[end..].chars();
+          let is_end = matches!(char, '.' | '!' | '?')
+            && match rest.next() {
+              None => true,
+              Some(next) => {
+                next.is_whitespace()
+                  && rest
+                    .find(|char| !char.is_whitespace())
+                    .is_some_and(char::is_uppercase)
+              }
+            };
+
+          if is_end {
+            remaining = remaining.saturating_sub(1);
+          }
+
+          (is_end && remaining == 0).then_some(end)
+        });
+
+        if let Some(end) = sentence_end {
+          literal.truncate(end);
+          truncated = true;
+        }
+      }
+    }
+  }
+}
+
 fn render_node<'a>(
   node: &'a AstNode<'a>,
   options: &comrak::Options,
@@ -430,6 +548,23 @@ fn render_node<'a>(
 pub struct MarkdownToHTMLOptions {
   pub title_only: bool,
   pub no_toc: bool,
+  /// The line from which to number the lines of code blocks.
+  pub line_numbers_start: Option<usize>,
+}
+
+/// A custom renderer for markdown in JSDoc, used instead of the built-in
+/// renderer, eg. to support additional markdown extensions.
+pub trait MarkdownRenderer {
+  /// Render the passed markdown to HTML. `{@link}` tags have already been
+  /// resolved to regular markdown links. If `options.title_only` is set, only
+  /// the first block should be rendered. Returns `None` if there is nothing
+  /// to render.
+  fn render(
+    &self,
+    render_ctx: &RenderContext,
+    md: &str,
+    options: &MarkdownToHTMLOptions,
+  ) -> Option<String>;
 }
 
 pub fn strip(render_ctx: &RenderContext, md: &str) -> String {
@@ -472,6 +607,22 @@ pub fn strip(render_ctx: &RenderContext, md: &str) -> String {
   String::from_utf8(bw.into_inner().unwrap()).unwrap()
 }
 
+/// Turn raw HTML written in the markdown into text, so that it is rendered
+/// escaped. HTML generated afterwards, eg. for alerts, is unaffected.
+fn escape_raw_html<'a>(root: &'a AstNode<'a>) {
+  for node in root.descendants() {
+    let mut data = node.data.borrow_mut();
+
+    let literal = match &mut data.value {
+      NodeValue::HtmlBlock(NodeHtmlBlock { literal, .. })
+      | NodeValue::HtmlInline(literal) => std::mem::take(literal),
+      _ => continue,
+    };
+
+    data.value = NodeValue::Text(literal);
+  }
+}
+
 pub fn markdown_to_html(
   render_ctx: &RenderContext,
   md: &str,
@@ -495,11 +646,23 @@ pub fn markdown_to_html(
     options.render.unsafe_ = true; // its fine because we run ammonia afterwards
   }
 
+  let excerpt_adapter = render_options.line_numbers_start.map(|start| {
+    super::comrak_adapters::HighlightAdapter {
+      language_cb: render_ctx.ctx.highlight_adapter.language_cb,
+      show_line_numbers: false,
+      copy_button: render_ctx.ctx.highlight_adapter.copy_button,
+      excerpt_start_line: Some(start),
+    }
+  });
+
   let mut plugins = comrak::Plugins::default();
 
   if !render_options.title_only {
-    plugins.render.codefence_syntax_highlighter =
-      Some(&render_ctx.ctx.highlight_adapter);
+    plugins.render.codefence_syntax_highlighter = Some(
+      excerpt_adapter
+        .as_ref()
+        .unwrap_or(&render_ctx.ctx.highlight_adapter),
+    );
     if !render_options.no_toc {
       plugins.render.heading_adapter = Some(&render_ctx.toc);
     }
@@ -513,14 +676,25 @@ pub fn markdown_to_html(
     "markdown"
   };
 
-  let html = {
+  let html = if let Some(markdown_renderer) = &render_ctx.ctx.markdown_renderer
+  {
+    markdown_renderer.render(render_ctx, &md, &render_options)?
+  } else {
     let arena = Arena::new();
     let root = comrak::parse_document(&arena, &md, &options);
 
+    if render_ctx.ctx.escape_html {
+      escape_raw_html(root);
+    }
+
     if render_options.title_only {
       walk_node_title(root);
 
       if let Some(child) = root.first_child() {
+        if let Some(length) = render_ctx.ctx.summary_length {
+          truncate_summary(child, length);
+        }
+
         render_node(child, &options, &plugins)
       } else {
         return None;
@@ -537,9 +711,10 @@ pub fn markdown_to_html(
       .set(Some(render_ctx.get_current_resolve().get_file().cloned()));
     URL_REWRITER.set(Some(render_ctx.ctx.url_rewriter.clone()));
 
+    let html = AMMONIA.clean(&html).to_string();
     let html = Some(format!(
       r#"<div class="{class_name}">{}</div>"#,
-      AMMONIA.clean(&html)
+      open_external_links(render_ctx, &html)
     ));
 
     CURRENT_FILE.set(None);
@@ -549,8 +724,51 @@ pub fn markdown_to_html(
   }
   #[cfg(not(feature = "ammonia"))]
   {
-    Some(format!(r#"<div class="{class_name}">{html}</div>"#))
+    Some(format!(
+      r#"<div class="{class_name}">{}</div>"#,
+      open_external_links(render_ctx, &html)
+    ))
+  }
+}
+
+/// Make links to other sites open in a new tab if `external_links_new_tab`
+/// is set.
+fn open_external_links<'a>(
+  render_ctx: &RenderContext,
+  html: &'a str,
+) -> Cow<'a, str> {
+  if !render_ctx.ctx.external_links_new_tab {
+    return Cow::Borrowed(html);
   }
+
+  EXTERNAL_LINK_RE.replace_all(html, |captures: &regex::Captures| {
+    let tag = &captures[0];
+
+    if tag.contains(" target=") {
+      return tag.to_string();
+    }
+
+    let tag = tag.strip_suffix('>').unwrap();
+
+    // keep the existing rel, eg. the `nofollow` added by ammonia
+    if let Some(start) = tag.find(r#" rel=""#) {
+      let value_start = start + r#" rel=""#.len();
+      let value_end = value_start + tag[value_start..].find('"').unwrap();
+      let value = &tag[value_start..value_end];
+
+      if value.split_whitespace().any(|rel| rel == "noopener") {
+        format!(r#"{tag} target="_blank">"#)
+      } else {
+        format!(
+          r#"{} noopener{} target="_blank">"#,
+          &tag[..value_end],
+          &tag[value_end..]
+        )
+      }
+    } else {
+      format!(r#"{tag} target="_blank" rel="noopener">"#)
+    }
+  })
 }
 
 pub(crate) fn render_markdown(
@@ -564,6 +782,7 @@ pub(crate) fn render_markdown(
     MarkdownToHTMLOptions {
       title_only: false,
       no_toc,
+      line_numbers_start: None,
     },
   )
   .unwrap_or_default()
@@ -574,40 +793,153 @@ pub(crate) fn jsdoc_body_to_html(
   js_doc: &JsDoc,
   summary: bool,
 ) -> Option<String> {
-  if let Some(doc) = js_doc.doc.as_deref() {
+  let body = js_doc.doc.as_deref().and_then(|doc| {
     markdown_to_html(
       ctx,
-      doc,
+      &strip_private_remarks(doc),
       MarkdownToHTMLOptions {
         title_only: summary,
         no_toc: false,
+        line_numbers_start: None,
       },
     )
-  } else {
-    None
+  });
+
+  if summary || !ctx.ctx.show_private_remarks {
+    return body;
   }
+
+  let remarks = js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| {
+      let JsDocTag::Unsupported { value } = tag else {
+        return None;
+      };
+
+      private_remarks(value)
+    })
+    .map(|remarks| render_markdown(ctx, remarks, true))
+    .collect::<String>();
+
+  if remarks.is_empty() {
+    return body;
+  }
+
+  Some(format!(
+    r#"{}<div class="privateRemarks"><div class="privateRemarksTitle">Private remarks</div>{remarks}</div>"#,
+    body.unwrap_or_default()
+  ))
+}
+
+/// The text of a TSDoc `@privateRemarks` tag, if the tag is one.
+fn private_remarks(tag: &str) -> Option<&str> {
+  let rest = tag.strip_prefix("@privateRemarks")?;
+
+  (rest.is_empty() || rest.starts_with(char::is_whitespace))
+    .then(|| rest.trim())
+}
+
+/// Remove `@privateRemarks` blocks which ended up in the markdown, up to the
+/// next tag or the end, as they must never be part of the public output.
+fn strip_private_remarks(doc: &str) -> Cow<str> {
+  if !doc.contains("@privateRemarks") {
+    return Cow::Borrowed(doc);
+  }
+
+  let mut out = String::with_capacity(doc.len());
+  let mut in_remarks = false;
+  let mut in_codeblock = false;
+
+  for line in doc.lines() {
+    let trimmed = line.trim_start();
+
+    if !in_codeblock {
+      if private_remarks(trimmed).is_some() {
+        in_remarks = true;
+        continue;
+      } else if trimmed.starts_with('@') {
+        in_remarks = false;
+      }
+    }
+
+    if trimmed.starts_with("```") {
+      in_codeblock = !in_codeblock;
+    }
+
+    if !in_remarks {
+      out.push_str(line);
+      out.push('\n');
+    }
+  }
+
+  Cow::Owned(out.trim_end().to_string())
 }
 
 pub(crate) fn jsdoc_examples(
   ctx: &RenderContext,
   js_doc: &JsDoc,
+  symbol: Option<&str>,
 ) -> Option<SectionCtx> {
+  examples_section(ctx, example_ctxs(ctx, js_doc, symbol))
+}
+
+/// Like [`jsdoc_examples`], but with the first example split off, to be
+/// rendered separately from the section with the remaining examples.
+pub(crate) fn jsdoc_examples_split_first(
+  ctx: &RenderContext,
+  js_doc: &JsDoc,
+  symbol: Option<&str>,
+) -> (Option<ExampleCtx>, Option<SectionCtx>) {
+  let mut examples = example_ctxs(ctx, js_doc, symbol);
+
+  if examples.is_empty() {
+    return (None, None);
+  }
+
+  let mut first = examples.remove(0);
+  let anchor = ctx.toc.anchorize(&first.id);
+  first.id = anchor.clone();
+  first.anchor.id = anchor;
+
+  (Some(first), examples_section(ctx, examples))
+}
+
+fn example_ctxs(
+  ctx: &RenderContext,
+  js_doc: &JsDoc,
+  symbol: Option<&str>,
+) -> Vec<ExampleCtx> {
+  let statuses = symbol.and_then(|symbol| {
+    ctx
+      .ctx
+      .example_statuses
+      .as_ref()
+      .and_then(|example_statuses| example_statuses.get(symbol))
+  });
   let mut i = 0;
 
-  let examples = js_doc
+  js_doc
     .tags
     .iter()
     .filter_map(|tag| {
       if let JsDocTag::Example { doc } = tag {
-        let example = ExampleCtx::new(ctx, doc, i);
+        let mut example = ExampleCtx::new(ctx, doc, i);
+        example.status =
+          statuses.and_then(|statuses| statuses.get(&i).copied());
         i += 1;
         Some(example)
       } else {
         None
       }
     })
-    .collect::<Vec<ExampleCtx>>();
+    .collect()
+}
 
+fn examples_section(
+  ctx: &RenderContext,
+  examples: Vec<ExampleCtx>,
+) -> Option<SectionCtx> {
   if !examples.is_empty() {
     Some(SectionCtx::new(
       ctx,
@@ -619,6 +951,56 @@ pub(crate) fn jsdoc_examples(
   }
 }
 
+pub(crate) fn jsdoc_custom_tags(
+  ctx: &RenderContext,
+  js_doc: &JsDoc,
+) -> Vec<SectionCtx> {
+  let Some(tag_renderers) = &ctx.ctx.tag_renderers else {
+    return vec![];
+  };
+
+  let mut rendered = IndexMap::<&str, Vec<String>>::new();
+
+  for tag in js_doc.tags.iter() {
+    let JsDocTag::Unsupported { value } = tag else {
+      continue;
+    };
+
+    let Some(value) = value.strip_prefix('@') else {
+      continue;
+    };
+
+    let (name, text) =
+      value.split_once(char::is_whitespace).unwrap_or((value, ""));
+
+    // private remarks are never public, even with a renderer registered
+    if name == "privateRemarks" {
+      continue;
+    }
+
+    if let Some((name, renderer)) = tag_renderers.get_key_value(name) {
+      rendered
+        .entry(name)
+        .or_default()
+        .push(renderer(ctx, text.trim()));
+    }
+  }
+
+  rendered
+    .into_iter()
+    .map(|(name, html)| {
+      let mut title = name.to_string();
+      if let Some(first) = title.get_mut(0..1) {
+        first.make_ascii_uppercase();
+      }
+
+      let mut section = SectionCtx::new(ctx, &title, SectionContentCtx::Empty);
+      section.header.doc = Some(html.join(""));
+      section
+    })
+    .collect()
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct ExampleCtx {
   pub anchor: AnchorCtx,
@@ -626,13 +1008,16 @@ pub struct ExampleCtx {
   pub title: String,
   pub markdown_title: String,
   markdown_body: String,
+  /// The rendered `imports` block of the example, shown collapsed above it.
+  setup: Option<String>,
+  pub status: Option<ExampleStatus>,
 }
 
 impl ExampleCtx {
   pub const TEMPLATE: &'static str = "example";
 
   pub fn new(render_ctx: &RenderContext, example: &str, i: usize) -> Self {
-    let id = name_to_id("example", &i.to_string());
+    let id = name_to_id(render_ctx.ctx, "example", &i.to_string());
 
     let (maybe_title, body) = split_markdown_title(example);
     let title = if let Some(title) = maybe_title {
@@ -642,8 +1027,20 @@ impl ExampleCtx {
     };
 
     let markdown_title = render_markdown(render_ctx, &title, false);
-    let markdown_body =
-      render_markdown(render_ctx, body.unwrap_or_default(), true);
+    let (setup, body) = split_example_setup(body.unwrap_or_default());
+    let setup = setup.map(|setup| {
+      render_markdown(render_ctx, &format!("```ts\n{setup}\n```"), true)
+    });
+    let markdown_body = markdown_to_html(
+      render_ctx,
+      &body,
+      MarkdownToHTMLOptions {
+        title_only: false,
+        no_toc: true,
+        line_numbers_start: render_ctx.ctx.example_line_numbers,
+      },
+    )
+    .unwrap_or_default();
 
     ExampleCtx {
       anchor: AnchorCtx { id: id.to_string() },
@@ -651,6 +1048,8 @@ impl ExampleCtx {
       title,
       markdown_title,
       markdown_body,
+      setup,
+      status: None,
     }
   }
 }
@@ -685,7 +1084,7 @@ impl ModuleDocCtx {
         }
       });
 
-      if let Some(examples) = jsdoc_examples(render_ctx, &node.js_doc) {
+      if let Some(examples) = jsdoc_examples(render_ctx, &node.js_doc, None) {
         sections.push(examples);
       }
 
@@ -693,7 +1092,14 @@ impl ModuleDocCtx {
 
       (deprecated, html)
     } else {
-      (None, None)
+      let readme = render_ctx
+        .ctx
+        .module_readmes
+        .as_ref()
+        .and_then(|readmes| readmes.get(&short_path.specifier))
+        .map(|readme| render_markdown(render_ctx, readme, false));
+
+      (None, readme)
     };
 
     if !short_path.is_main {
@@ -731,9 +1137,12 @@ impl ModuleDocCtx {
 mod test {
   use crate::html::href_path_resolve;
   use crate::html::jsdoc::parse_links;
+  use crate::html::util::SectionContentCtx;
+  use crate::html::ExampleStatus;
   use crate::html::GenerateCtx;
   use crate::html::GenerateOptions;
   use crate::html::HrefResolver;
+  use crate::html::SummaryLength;
   use crate::DocNode;
   use crate::Location;
   use deno_ast::ModuleSpecifier;
@@ -787,20 +1196,28 @@ mod test {
     }
   }
 
+  /// A render context without any doc nodes. The generate context is leaked,
+  /// so that the render context can borrow it for the rest of the test.
+  fn render_ctx_with(options: GenerateOptions) -> RenderContext<'static> {
+    let ctx = GenerateCtx::new(
+      options,
+      Default::default(),
+      Default::default(),
+      Default::default(),
+    )
+    .unwrap();
+
+    RenderContext::new(
+      Box::leak(Box::new(ctx)),
+      &[],
+      UrlResolveKind::AllSymbols,
+    )
+  }
+
   #[test]
   fn parse_links_test() {
     let ctx = GenerateCtx::new(
-      GenerateOptions {
-        package_name: None,
-        main_entrypoint: None,
-        href_resolver: std::rc::Rc::new(EmptyResolver {}),
-        usage_composer: None,
-        rewrite_map: None,
-        category_docs: None,
-        disable_search: false,
-        symbol_redirect_map: None,
-        default_symbol_map: None,
-      },
+      GenerateOptions::new(std::rc::Rc::new(EmptyResolver {})),
       Default::default(),
       Default::default(),
       IndexMap::from([
@@ -911,6 +1328,21 @@ mod test {
       parse_links("foo {@linkcode unknownSymbol} bar", &render_ctx),
       "foo `unknownSymbol` bar"
     );
+    assert_eq!(
+      parse_links("foo {@link unknownSymbol | unknown} bar", &render_ctx),
+      "foo unknown bar"
+    );
+    assert_eq!(
+      parse_links("foo {@linkplain unknownSymbol} bar", &render_ctx),
+      "foo unknownSymbol bar"
+    );
+    assert_eq!(
+      parse_links(
+        "foo {@linkplain https://example.com Example} bar",
+        &render_ctx
+      ),
+      "foo [Example](https://example.com) bar"
+    );
 
     #[cfg(not(target_os = "windows"))]
     {
@@ -922,6 +1354,18 @@ mod test {
         parse_links("foo {@linkcode bar} bar", &render_ctx),
         "foo [`bar`](../../.././/a.ts/~/bar.html) bar"
       );
+      assert_eq!(
+        parse_links("foo {@linkplain bar} bar", &render_ctx),
+        "foo [bar](../../.././/a.ts/~/bar.html) bar"
+      );
+      assert_eq!(
+        parse_links("foo {@link bar | the bar} bar", &render_ctx),
+        "foo [the bar](../../.././/a.ts/~/bar.html) bar"
+      );
+      assert_eq!(
+        parse_links("foo {@link bar\nthe bar} bar", &render_ctx),
+        "foo [the bar](../../.././/a.ts/~/bar.html) bar"
+      );
 
       assert_eq!(
         parse_links("foo {@link [b.ts]} bar", &render_ctx),
@@ -945,25 +1389,8 @@ mod test {
 
   #[test]
   fn markdown_alerts() {
-    let ctx = GenerateCtx::new(
-      GenerateOptions {
-        package_name: None,
-        main_entrypoint: None,
-        href_resolver: std::rc::Rc::new(EmptyResolver {}),
-        usage_composer: None,
-        rewrite_map: None,
-        category_docs: None,
-        disable_search: false,
-        symbol_redirect_map: None,
-        default_symbol_map: None,
-      },
-      Default::default(),
-      Default::default(),
-      Default::default(),
-    )
-    .unwrap();
-
-    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    let render_ctx =
+      render_ctx_with(GenerateOptions::new(std::rc::Rc::new(EmptyResolver {})));
 
     let md = super::render_markdown(
       &render_ctx,
@@ -991,5 +1418,313 @@ mod test {
 
     assert!(md.contains("foo"));
     assert!(md.contains("bar"));
+
+    let md =
+      super::render_markdown(&render_ctx, "> [!warning] Careful\n> foo", true);
+    assert!(md.contains("alert-warning"));
+    assert!(md.contains("Careful"));
+
+    let md = super::render_markdown(&render_ctx, "> [!UNKNOWN]\n> foo", true);
+    assert!(!md.contains("alert"));
+    assert!(md.contains("<blockquote>"));
+  }
+
+  #[test]
+  fn custom_tag_renderers() {
+    let mut tag_renderers = IndexMap::new();
+    tag_renderers.insert(
+      "platform".to_string(),
+      std::rc::Rc::new(|_: &RenderContext, text: &str| format!("<b>{text}</b>"))
+        as crate::html::TagRenderer,
+    );
+
+    let render_ctx = render_ctx_with(GenerateOptions {
+      tag_renderers: Some(tag_renderers),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let js_doc = JsDoc::from(
+      "foo\n@platform linux\n@platform darwin\n@unknown bar".to_string(),
+    );
+
+    let sections = super::jsdoc_custom_tags(&render_ctx, &js_doc);
+
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].header.title, "Platform");
+    assert_eq!(
+      sections[0].header.doc.as_deref(),
+      Some("<b>linux</b><b>darwin</b>")
+    );
+  }
+  #[test]
+  fn example_statuses() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      example_statuses: Some(IndexMap::from([(
+        "foo".to_string(),
+        IndexMap::from([
+          (0, ExampleStatus::Passed),
+          (2, ExampleStatus::Failed),
+        ]),
+      )])),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let js_doc = JsDoc::from(
+      "@example a\n```ts\n1\n```\n@example b\n@example c".to_string(),
+    );
+
+    let statuses = |symbol| {
+      let section =
+        super::jsdoc_examples(&render_ctx, &js_doc, symbol).unwrap();
+      let SectionContentCtx::Example(examples) = section.content else {
+        unreachable!()
+      };
+      examples
+        .into_iter()
+        .map(|example| example.status)
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+      statuses(Some("foo")),
+      [
+        Some(ExampleStatus::Passed),
+        None,
+        Some(ExampleStatus::Failed)
+      ]
+    );
+    assert_eq!(statuses(Some("bar")), [None, None, None]);
+    assert_eq!(statuses(None), [None, None, None]);
+  }
+
+  #[test]
+  fn example_setup() {
+    assert_eq!(
+      super::split_example_setup(
+        "```imports\nimport { foo } from \"./mod.ts\";\n```\n```ts\nfoo();\n```"
+      ),
+      (
+        Some("import { foo } from \"./mod.ts\";"),
+        "```ts\nfoo();\n```".into()
+      )
+    );
+    assert_eq!(
+      super::split_example_setup("```ts\nfoo();\n```"),
+      (None, "```ts\nfoo();\n```".into())
+    );
+  }
+
+  #[test]
+  fn external_links_new_tab() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      external_links_new_tab: true,
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let md = super::render_markdown(
+      &render_ctx,
+      "[site](https://example.com) and [local](./mod.ts)",
+      true,
+    );
+
+    let external =
+      regex::Regex::new(r#"<a [^>]*href="https://example.com"[^>]*>"#)
+        .unwrap()
+        .find(&md)
+        .unwrap()
+        .as_str();
+    assert!(external.contains(r#" target="_blank""#));
+    #[cfg(feature = "ammonia")]
+    assert!(external.contains(r#" rel="nofollow noopener""#));
+    #[cfg(not(feature = "ammonia"))]
+    assert!(external.contains(r#" rel="noopener""#));
+
+    let local = regex::Regex::new(r#"<a [^>]*href="./mod.ts"[^>]*>"#)
+      .unwrap()
+      .find(&md)
+      .unwrap()
+      .as_str();
+    assert!(!local.contains(" target="));
+    assert!(!local.contains("noopener"));
+  }
+
+  #[test]
+  fn private_remarks() {
+    let js_doc = JsDoc::from(
+      "Public.\n@privateRemarks Internal detail.\n@privateRemarksFoo bar"
+        .to_string(),
+    );
+
+    let render_ctx =
+      render_ctx_with(GenerateOptions::new(std::rc::Rc::new(EmptyResolver {})));
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, false).unwrap(),
+      "<div class=\"markdown\"><p>Public.</p>\n</div>"
+    );
+    assert_eq!(
+      super::strip_private_remarks(
+        "Public.\n@privateRemarks Internal\ndetail.\n@see other"
+      ),
+      "Public.\n@see other"
+    );
+
+    let render_ctx = render_ctx_with(GenerateOptions {
+      show_private_remarks: true,
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, false).unwrap(),
+      "<div class=\"markdown\"><p>Public.</p>\n</div><div class=\"privateRemarks\"><div class=\"privateRemarksTitle\">Private remarks</div><div class=\"markdown\"><p>Internal detail.</p>\n</div></div>"
+    );
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, true).unwrap(),
+      "<div class=\"markdown_summary\"><p>Public.</p>\n</div>"
+    );
+  }
+
+  #[test]
+  fn anchor_id_generator() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      anchor_id_generator: Some(std::rc::Rc::new(|kind, name| {
+        (kind == "example").then(|| format!("ex-{name}"))
+      })),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    assert_eq!(super::ExampleCtx::new(&render_ctx, "foo", 0).id, "ex-0");
+    assert_eq!(
+      crate::html::util::name_to_id(render_ctx.ctx, "function", "foo.bar"),
+      "function_foo_bar"
+    );
+  }
+
+  #[test]
+  fn example_line_numbers() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      example_line_numbers: Some(10),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let example = super::ExampleCtx::new(
+      &render_ctx,
+      "```ts\nconst a = 1;\nconst b = 2;\n```",
+      0,
+    );
+    assert!(example.markdown_body.contains(
+      r#"<div class="lineNumbers"><span class="block">10</span><span class="block">11</span></div>"#
+    ));
+    assert!(example
+      .markdown_body
+      .contains("data-copy=\"const a = 1;\nconst b = 2;\n\""));
+    assert!(!example.markdown_body.contains(r#"id="L1""#));
+  }
+
+  #[test]
+  fn summary_length() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      summary_length: Some(SummaryLength::Sentences(1)),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let js_doc = JsDoc::from(
+      "The first sentence, eg. `foo.bar`. The second one!\n\nbody".to_string(),
+    );
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, true).unwrap(),
+      "<div class=\"markdown_summary\"><p>The first sentence, eg. <code>foo.bar</code>.</p>\n</div>"
+    );
+
+    let render_ctx = render_ctx_with(GenerateOptions {
+      summary_length: Some(SummaryLength::Characters(24)),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, true).unwrap(),
+      "<div class=\"markdown_summary\"><p>The first sentence, eg. …</p>\n</div>"
+    );
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, false).unwrap(),
+      "<div class=\"markdown\"><p>The first sentence, eg. <code>foo.bar</code>. The second one!</p>\n<p>body</p>\n</div>"
+    );
+  }
+
+  #[test]
+  fn no_script() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      no_script: true,
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let md =
+      super::render_markdown(&render_ctx, "```ts\nconst a = 1;\n```", true);
+
+    assert!(md.contains("const"));
+    assert!(!md.contains("data-copy"));
+    assert!(render_ctx.ctx.disable_search);
+  }
+
+  struct UppercaseRenderer {}
+
+  impl super::MarkdownRenderer for UppercaseRenderer {
+    fn render(
+      &self,
+      _render_ctx: &RenderContext,
+      md: &str,
+      options: &super::MarkdownToHTMLOptions,
+    ) -> Option<String> {
+      let md = if options.title_only {
+        md.split("\n\n").next()?
+      } else {
+        md
+      };
+
+      Some(format!("<p>{}</p>", md.to_uppercase()))
+    }
+  }
+
+  #[test]
+  fn custom_markdown_renderer() {
+    let render_ctx = render_ctx_with(GenerateOptions {
+      markdown_renderer: Some(std::rc::Rc::new(UppercaseRenderer {})),
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+
+    let js_doc = JsDoc::from("summary\n\nbody".to_string());
+
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, true).unwrap(),
+      r#"<div class="markdown_summary"><p>SUMMARY</p></div>"#
+    );
+    assert_eq!(
+      super::jsdoc_body_to_html(&render_ctx, &js_doc, false).unwrap(),
+      "<div class=\"markdown\"><p>SUMMARY\n\nBODY</p></div>"
+    );
+  }
+
+  #[test]
+  fn escape_html() {
+    let md =
+      "<script>alert(1)</script>\n\nfoo <img src=\"x.png\" onerror=\"alert(2)\"> bar";
+
+    let render_ctx =
+      render_ctx_with(GenerateOptions::new(std::rc::Rc::new(EmptyResolver {})));
+    let html = super::render_markdown(&render_ctx, md, false);
+    assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    // raw HTML is passed through to ammonia if enabled, and escaped by comrak
+    // otherwise
+    #[cfg(feature = "ammonia")]
+    assert!(html.contains(r#"<p>foo <img src="x.png"> bar</p>"#));
+    #[cfg(not(feature = "ammonia"))]
+    assert!(html.contains("&lt;img src=&quot;x.png&quot;"));
+
+    let render_ctx = render_ctx_with(GenerateOptions {
+      escape_html: true,
+      ..GenerateOptions::new(std::rc::Rc::new(EmptyResolver {}))
+    });
+    let html = super::render_markdown(&render_ctx, md, false);
+    assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(html.contains("<p>foo &lt;img src="));
+    assert!(!html.contains("<img"));
+    assert!(!html.contains("<script"));
   }
 }
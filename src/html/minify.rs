@@ -0,0 +1,277 @@
+/// Minify a stylesheet by removing comments and all whitespace which is not
+/// significant, eg. around braces and after declarations.
+pub(crate) fn minify_css(source: &str) -> String {
+  let mut out = String::with_capacity(source.len());
+  let mut chars = source.chars().peekable();
+  let mut pending_space = false;
+
+  while let Some(char) = chars.next() {
+    match char {
+      '/' if chars.peek() == Some(&'*') => {
+        chars.next();
+        skip_block_comment(&mut chars);
+        pending_space = true;
+      }
+      char if char.is_whitespace() => pending_space = true,
+      '{' | '}' | ';' | ',' => {
+        if char == '}' && out.ends_with(';') {
+          out.pop();
+        }
+
+        out.push(char);
+        pending_space = false;
+      }
+      _ => {
+        if pending_space
+          && !out.is_empty()
+          && !out.ends_with(['{', '}', ';', ',', ':'])
+        {
+          out.push(' ');
+        }
+        pending_space = false;
+
+        out.push(char);
+
+        if char == '"' || char == '\'' {
+          copy_string(&mut out, &mut chars, char);
+        }
+      }
+    }
+  }
+
+  out
+}
+
+/// Minify a script by removing comments, indentation and blank lines.
+/// Line breaks are kept so that automatic semicolon insertion is unaffected,
+/// and the contents of string, template and regular expression literals are
+/// left untouched.
+pub(crate) fn minify_js(source: &str) -> String {
+  let mut out = String::with_capacity(source.len());
+  let mut line = String::new();
+  let mut chars = source.chars().peekable();
+  // brace depth of each template literal substitution currently being scanned
+  let mut substitutions: Vec<usize> = vec![];
+  let mut in_template = false;
+
+  while let Some(char) = chars.next() {
+    if in_template {
+      line.push(char);
+
+      match char {
+        '\\' => line.extend(chars.next()),
+        '`' => in_template = false,
+        '$' if chars.peek() == Some(&'{') => {
+          line.extend(chars.next());
+          substitutions.push(0);
+          in_template = false;
+        }
+        _ => {}
+      }
+
+      continue;
+    }
+
+    match char {
+      '/' if chars.peek() == Some(&'/') => {
+        while chars.next_if(|char| *char != '\n').is_some() {}
+      }
+      '/' if chars.peek() == Some(&'*') => {
+        chars.next();
+        skip_block_comment(&mut chars);
+        line.push(' ');
+      }
+      '"' | '\'' => {
+        line.push(char);
+        copy_string(&mut line, &mut chars, char);
+      }
+      '/'
+        if starts_regex(if line.trim().is_empty() { &out } else { &line }) =>
+      {
+        line.push(char);
+        copy_regex(&mut line, &mut chars);
+      }
+      '`' => {
+        line.push(char);
+        in_template = true;
+      }
+      '{' => {
+        if let Some(depth) = substitutions.last_mut() {
+          *depth += 1;
+        }
+        line.push(char);
+      }
+      '}' => {
+        line.push(char);
+
+        match substitutions.last_mut() {
+          Some(0) => {
+            substitutions.pop();
+            in_template = true;
+          }
+          Some(depth) => *depth -= 1,
+          None => {}
+        }
+      }
+      '\n' => push_line(&mut out, &mut line),
+      _ => line.push(char),
+    }
+  }
+
+  push_line(&mut out, &mut line);
+
+  out
+}
+
+fn push_line(out: &mut String, line: &mut String) {
+  let trimmed = line.trim();
+
+  if !trimmed.is_empty() {
+    if !out.is_empty() {
+      out.push('\n');
+    }
+    out.push_str(trimmed);
+  }
+
+  line.clear();
+}
+
+/// Whether a `/` following the passed source starts a regular expression
+/// literal rather than being a division operator, which is the case after an
+/// operator, an opening bracket or a keyword.
+fn starts_regex(preceding: &str) -> bool {
+  const KEYWORDS: &[&str] = &[
+    "return",
+    "typeof",
+    "instanceof",
+    "in",
+    "of",
+    "new",
+    "delete",
+    "void",
+    "throw",
+    "case",
+    "do",
+    "else",
+    "yield",
+    "await",
+  ];
+
+  let preceding = preceding.trim_end();
+  let Some(last) = preceding.chars().last() else {
+    return true;
+  };
+
+  if last.is_alphanumeric() || matches!(last, '_' | '$') {
+    let word_start = preceding
+      .rfind(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$')))
+      .map_or(0, |i| i + 1);
+    KEYWORDS.contains(&&preceding[word_start..])
+  } else {
+    !matches!(last, ')' | ']' | '}' | '"' | '\'' | '`')
+  }
+}
+
+fn copy_regex(out: &mut String, chars: &mut impl Iterator<Item = char>) {
+  let mut in_class = false;
+
+  while let Some(char) = chars.next() {
+    out.push(char);
+
+    match char {
+      '\\' => out.extend(chars.next()),
+      '[' => in_class = true,
+      ']' => in_class = false,
+      '/' if !in_class => break,
+      '\n' => break,
+      _ => {}
+    }
+  }
+}
+
+fn skip_block_comment(chars: &mut impl Iterator<Item = char>) {
+  let mut prev = None;
+
+  for char in chars {
+    if prev == Some('*') && char == '/' {
+      break;
+    }
+    prev = Some(char);
+  }
+}
+
+fn copy_string(
+  out: &mut String,
+  chars: &mut impl Iterator<Item = char>,
+  quote: char,
+) {
+  while let Some(char) = chars.next() {
+    out.push(char);
+
+    if char == '\\' {
+      out.extend(chars.next());
+    } else if char == quote || char == '\n' {
+      break;
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn css() {
+    assert_eq!(
+      minify_css(
+        r#"
+/* usage */
+#deno:checked ~ *:last-child > :not(#deno_content) {
+  display: none;
+}
+.ddoc .sectionToggle::before {
+  content: "a  b";
+  margin: 0 2px;
+}
+"#
+      ),
+      r#"#deno:checked ~ *:last-child > :not(#deno_content){display:none}.ddoc .sectionToggle::before{content:"a  b";margin:0 2px}"#
+    );
+  }
+
+  #[test]
+  fn js() {
+    assert_eq!(
+      minify_js(
+        r#"
+// leading comment
+function foo(a, b) {
+  /* block */
+  const url = "http://example.com"; // trailing comment
+
+  return `<a href="${url}">
+  ${a ? { b }.b : "//"}
+</a>`;
+}
+"#
+      ),
+      "function foo(a, b) {\nconst url = \"http://example.com\";\nreturn `<a href=\"${url}\">\n  ${a ? { b }.b : \"//\"}\n</a>`;\n}"
+    );
+  }
+
+  #[test]
+  fn js_regex() {
+    assert_eq!(
+      minify_js(
+        r#"
+const prompt = /^\s*[$>] /gm; // strip prompts
+const quote = /["'`/]/;
+const slashes = text.replace(/\/\//g, "/");
+const half = a / 2 / b; // not a regex
+if (/*a*/ /x/.test(y)) return /z/;
+"#
+      ),
+      "const prompt = /^\\s*[$>] /gm;\nconst quote = /[\"'`/]/;\nconst slashes = text.replace(/\\/\\//g, \"/\");\nconst half = a / 2 / b;\nif (  /x/.test(y)) return /z/;"
+    );
+  }
+}
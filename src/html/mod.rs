@@ -7,11 +7,14 @@ use handlebars::Handlebars;
 use indexmap::IndexMap;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 pub mod comrak_adapters;
+mod declaration;
 pub mod jsdoc;
+mod minify;
 pub mod pages;
 mod parameters;
 pub mod partition;
@@ -27,7 +30,9 @@ use crate::html::pages::SymbolPage;
 use crate::js_doc::JsDocTag;
 pub use pages::generate_symbol_pages_for_module;
 pub use render_context::RenderContext;
+pub use search::generate_llms_txt;
 pub use search::generate_search_index;
+pub use search::generate_symbol_list;
 pub use symbols::namespace;
 pub use symbols::SymbolContentCtx;
 pub use symbols::SymbolGroupCtx;
@@ -56,6 +61,8 @@ pub const RESET_STYLESHEET: &str =
 pub const RESET_STYLESHEET_FILENAME: &str = "reset.css";
 
 const SEARCH_INDEX_FILENAME: &str = "search_index.js";
+const SYMBOL_LIST_FILENAME: &str = "symbols.json";
+const LLMS_TXT_FILENAME: &str = "llms.txt";
 
 pub const SCRIPT_JS: &str = include_str!("./templates/script.js");
 pub const SCRIPT_FILENAME: &str = "script.js";
@@ -165,6 +172,10 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
     include_str!("./templates/category_panel.hbs"),
   )?;
   reg.register_template_string("see", include_str!("./templates/see.hbs"))?;
+  reg.register_template_string(
+    "compact_params",
+    include_str!("./templates/compact_params.hbs"),
+  )?;
 
   // pages
   reg.register_template_string(
@@ -183,6 +194,10 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
     pages::IndexCtx::TEMPLATE,
     include_str!("./templates/pages/index.hbs"),
   )?;
+  reg.register_template_string(
+    pages::SinglePageCtx::TEMPLATE,
+    include_str!("./templates/pages/single_page.hbs"),
+  )?;
   reg.register_template_string(
     "pages/top_nav",
     include_str!("./templates/pages/top_nav.hbs"),
@@ -195,6 +210,10 @@ fn setup_hbs() -> Result<Handlebars<'static>, anyhow::Error> {
     "pages/redirect",
     include_str!("./templates/pages/redirect.hbs"),
   )?;
+  reg.register_template_string(
+    "pages/footer",
+    include_str!("./templates/pages/footer.hbs"),
+  )?;
 
   // icons
   reg.register_template_string(
@@ -233,6 +252,46 @@ pub type UsageComposer = Rc<
   ) -> IndexMap<UsageComposerEntry, String>,
 >;
 
+/// Renders the text of a custom JSDoc tag, eg. `@platform`, to HTML.
+pub type TagRenderer = Rc<dyn Fn(&RenderContext, &str) -> String>;
+
+/// Generates the id of an anchor from its prefix, eg. `function`, and the
+/// name of the item, or `None` to use the default id.
+pub type AnchorIdGenerator = Rc<dyn Fn(&str, &str) -> Option<String>>;
+
+/// The result of running an example as a test, rendered as a badge next to
+/// the example.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExampleStatus {
+  Passed,
+  Failed,
+  Skipped,
+}
+
+/// Where the examples of a function are rendered relative to its description
+/// and the other sections, eg. parameters.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ExamplesPosition {
+  /// Before the description.
+  BeforeDocs,
+  /// After the description, before all other sections.
+  #[default]
+  AfterDocs,
+  /// After all other sections.
+  End,
+}
+
+/// How much of the first paragraph of the documentation of a symbol is shown
+/// as its summary.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SummaryLength {
+  /// At most this many characters, cut at a word and followed by an ellipsis.
+  Characters(usize),
+  /// At most this many sentences.
+  Sentences(usize),
+}
+
 #[derive(Eq, PartialEq, Hash)]
 pub struct UsageComposerEntry {
   pub name: String,
@@ -249,11 +308,272 @@ pub struct GenerateOptions {
   pub main_entrypoint: Option<ModuleSpecifier>,
   pub href_resolver: Rc<dyn HrefResolver>,
   pub usage_composer: Option<UsageComposer>,
+  /// Map each entrypoint to the directory its pages are written to, eg.
+  /// `packages/foo` for the entrypoint of a package in a monorepo. Links
+  /// between pages are resolved relative to these directories.
   pub rewrite_map: Option<IndexMap<ModuleSpecifier, String>>,
   pub category_docs: Option<IndexMap<String, Option<String>>>,
   pub disable_search: bool,
   pub symbol_redirect_map: Option<IndexMap<String, IndexMap<String, String>>>,
   pub default_symbol_map: Option<IndexMap<String, String>>,
+  /// The heading level used for section headings, eg. `2` renders them as
+  /// `<h2>`. Useful when embedding the output inside a larger document.
+  pub heading_level: u8,
+  /// Whether to omit the "Return Type" section of functions which have no
+  /// return type or return `void`. Functions returning `undefined` are kept,
+  /// as the two are not interchangeable for callbacks.
+  pub hide_void_return_type: bool,
+  /// Whether to add a toggle to each symbol which switches to a synthesized
+  /// `.d.ts` declaration of the symbol.
+  pub declaration_view: bool,
+  /// Whether to render unions consisting solely of string, number, bigint or
+  /// boolean literals as a list of the allowed values.
+  pub literal_union_list: bool,
+  /// Renderers for custom JSDoc tags, keyed by the tag name without the `@`.
+  /// Each tag with a registered renderer is rendered in a dedicated section.
+  pub tag_renderers: Option<IndexMap<String, TagRenderer>>,
+  /// Whether to render every symbol on a single `index.html` page with a
+  /// sidebar linking to each symbol, instead of a page per symbol.
+  pub single_page: bool,
+  /// Whether to display the type of optional parameters as a union with
+  /// `undefined`, eg. `T | undefined`, instead of only marking them optional.
+  pub optional_param_undefined: bool,
+  /// Base URL of the TypeScript handbook, used to link intrinsic utility types
+  /// like `Awaited` or `NoInfer`, eg.
+  /// `https://www.typescriptlang.org/docs/handbook/`. These types are not
+  /// linked if `None`.
+  pub intrinsic_type_docs_base: Option<String>,
+  /// Whether to hide parameters whose `@param` documentation starts with an
+  /// `@internal` marker. Such parameters are dimmed otherwise.
+  pub hide_internal_params: bool,
+  /// The statuses of examples which were run as tests, keyed by the qualified
+  /// name of the symbol and then the index of the example in its JSDoc.
+  pub example_statuses:
+    Option<IndexMap<String, IndexMap<usize, ExampleStatus>>>,
+  /// The specifier the package is imported from, eg. `jsr:@std/fs`. If set,
+  /// the import statement shown in the usage of each symbol is built from it
+  /// instead of [`HrefResolver::resolve_usage`], with the path of the module
+  /// appended for modules other than the main entrypoint.
+  pub import_specifier: Option<String>,
+  /// Whether to minify the emitted scripts and stylesheets, including the
+  /// styles generated for usage blocks. They are emitted in a readable form
+  /// otherwise.
+  pub minify_assets: bool,
+  /// A custom renderer for markdown in JSDoc. The built-in renderer is used
+  /// if `None`.
+  pub markdown_renderer: Option<Rc<dyn jsdoc::MarkdownRenderer>>,
+  /// Whether to render symbols which have an entry in `symbol_redirect_map`
+  /// as stub pages redirecting to their canonical definition, instead of
+  /// duplicating their documentation. Such symbols are also omitted from the
+  /// search index.
+  pub reexport_stubs: bool,
+  /// Whether to add a note with the type a returned `Promise` resolves to in
+  /// the "Return Type" section of functions, eg. "resolves to `T`" for
+  /// `Promise<T>`.
+  pub awaited_return_type: bool,
+  /// Raw HTML injected at the end of the `<head>` of every page, eg. an
+  /// analytics `<script>` tag.
+  pub inject_head: Option<String>,
+  /// Raw HTML injected at the start of the `<body>` of every page.
+  pub inject_body: Option<String>,
+  /// The date or version each symbol was added in, eg. `2024-05-01` or
+  /// `1.2.0`, keyed by the qualified name of the symbol. Symbols without an
+  /// entry fall back to their `@since` tag.
+  pub symbol_added: Option<IndexMap<String, String>>,
+  /// Symbols added on or after this date or version get a "New" badge. No
+  /// badges are rendered if `None`.
+  pub new_since: Option<String>,
+  /// Render function overloads sorted most specific first, ie. overloads with
+  /// fewer type parameters and more literal typed parameters first, instead of
+  /// in source order.
+  pub sort_overloads: bool,
+  /// The markdown contents of the README linked to each module, rendered at
+  /// the top of the index page of modules which have no module doc comment.
+  pub module_readmes: Option<IndexMap<ModuleSpecifier, String>>,
+  /// Modules which are experimental, in addition to those with a module-level
+  /// `@experimental` tag. Every symbol page of an experimental module starts
+  /// with a disclaimer banner.
+  pub experimental_modules: Option<Vec<ModuleSpecifier>>,
+  /// The markdown text of the disclaimer banner of experimental modules. A
+  /// generic disclaimer is used if `None`.
+  pub experimental_banner: Option<String>,
+  /// Parameter types whose text is longer than this number of characters are
+  /// collapsed to their name, with a toggle to show the full type. All types
+  /// are rendered in full if `None`.
+  pub collapse_param_types_over: Option<usize>,
+  /// Whether to show the name of referenced types qualified with the module
+  /// they are resolved from, eg. `pkg/mod.Foo`, in a tooltip.
+  pub qualified_type_names: bool,
+  /// Whether to escape raw HTML in JSDoc markdown instead of passing it
+  /// through, eg. for docs built from untrusted sources.
+  pub escape_html: bool,
+  /// Whether to show the path of the file each symbol is defined in as a
+  /// caption below its name, linked to the source if
+  /// [`HrefResolver::resolve_source`] returns a link.
+  pub source_path_caption: bool,
+  /// The prefix stripped from the file name of symbols in the source path
+  /// caption, eg. `file:///home/user/project/`. The path relative to the
+  /// root of the documented files is shown if `None`.
+  pub source_path_root: Option<String>,
+  /// Whether to group the overloads of functions by their return type, with
+  /// a heading for each return type, if they have different return types.
+  pub group_overloads_by_return_type: bool,
+  /// Where the examples of functions are rendered. Defaults to right after
+  /// the description.
+  pub examples_position: ExamplesPosition,
+  /// Also emit `symbols.json`, a flat list of all symbols with their kind,
+  /// signature and a one line description as plain text.
+  pub symbol_list: bool,
+  /// Render the signature of deprecated functions with a strike-through.
+  pub strike_deprecated_signatures: bool,
+  /// Render the return type in the summary of a function the same way as in
+  /// its return type section, eg. linking the parameter of a type predicate.
+  pub link_summary_return_type: bool,
+  /// Render the parameters of functions as a compact comma separated list,
+  /// with the type and description of each parameter shown in a tooltip,
+  /// instead of a list of entries.
+  pub compact_params: bool,
+  /// Open links to other sites, eg. in markdown and `@see` tags, in a new
+  /// tab. Links to symbols are unaffected.
+  pub external_links_new_tab: bool,
+  /// Render `never` types with a tooltip explaining them, as a parameter
+  /// typed `never` is usually an exhaustiveness check rather than meant to
+  /// be called.
+  pub never_type_note: bool,
+  /// Emit fully static pages without any scripts, eg. for sites with a
+  /// strict Content Security Policy. This disables search and copy buttons,
+  /// leaving only the CSS-only interactivity such as the declaration toggle
+  /// and the collapsed parameter types.
+  /// `inject_head` and `inject_body` are still emitted as given, so they
+  /// have to be kept free of scripts by the caller.
+  pub no_script: bool,
+  /// A nonce applied to the inline `<style>` and the `<script>` tags of every
+  /// page, for sites with a nonce-based Content Security Policy. It should be
+  /// freshly generated for each run.
+  pub csp_nonce: Option<String>,
+  /// Render union return types of functions with their members sorted and
+  /// duplicates removed, instead of in their original order.
+  pub sort_union_return_types: bool,
+  /// The time the documentation was generated at, shown in a "Generated on"
+  /// footer of every page, eg. created with [`iso_8601_timestamp`]. Not set
+  /// by default, so that the output stays reproducible.
+  pub generated_at: Option<String>,
+  /// Render the first example of functions right after their description,
+  /// above the parameters, with the remaining examples staying in the
+  /// "Examples" section.
+  pub inline_first_example: bool,
+  /// The URL the documentation is hosted at, eg. `https://example.com/docs/`.
+  /// When set, symbol pages link to their canonical URL with
+  /// `<link rel="canonical">`, which is the page of the canonical definition
+  /// from `symbol_redirect_map` for re-exported symbols.
+  pub canonical_base_url: Option<String>,
+  /// Render the comment following the type of a parameter, eg. `px` in
+  /// `x: number /* px */`, as a note next to the type.
+  pub param_type_comments: bool,
+  /// The maximum length of the summaries of symbols shown in listings, eg.
+  /// on index pages. The full documentation on symbol pages is unaffected.
+  pub summary_length: Option<SummaryLength>,
+  /// Show line numbers in the gutter of the code in examples, starting at
+  /// the passed line, eg. for excerpts of source referenced by line in the
+  /// surrounding prose. Copying the code excludes the line numbers.
+  pub example_line_numbers: Option<usize>,
+  /// Merge overloads which only differ by the type of a single parameter,
+  /// eg. for string literal dispatch, into one signature with that parameter
+  /// typed as the union of the types.
+  pub merge_overloads: bool,
+  /// Override the generation of the ids of anchors, eg. to match the scheme
+  /// of an existing documentation site for redirects. Returning `None` falls
+  /// back to the default id.
+  pub anchor_id_generator: Option<AnchorIdGenerator>,
+  /// Move deprecated overloads after the other overloads, instead of keeping
+  /// them in source order.
+  pub deprecated_overloads_last: bool,
+  /// Render the TSDoc `@privateRemarks` of symbols in a marked block, eg. for
+  /// debugging. They are omitted otherwise.
+  pub show_private_remarks: bool,
+  /// Render links to the sections of a function, eg. Parameters and Return
+  /// Type, above them if it has at least this many sections.
+  pub section_toc_threshold: Option<usize>,
+  /// Also emit `llms.txt`, a markdown summary of the public API with the
+  /// description and signature of each symbol, for AI tools.
+  pub llms_txt: bool,
+  /// Map the path of a module, as used for `symbol_redirect_map`, to the
+  /// previous names of its renamed symbols and their current names. A stub
+  /// page redirecting to the current page is rendered for each previous name
+  /// which no longer has a page of its own, so that links to it keep working.
+  /// Ignored in single page mode.
+  pub renamed_symbols: Option<IndexMap<String, IndexMap<String, String>>>,
+}
+
+impl GenerateOptions {
+  /// Options with every feature disabled and section headings rendered as
+  /// `<h2>`, so that callers only need to set the options they use, eg.
+  /// `GenerateOptions { single_page: true, ..GenerateOptions::new(resolver) }`.
+  pub fn new(href_resolver: Rc<dyn HrefResolver>) -> Self {
+    Self {
+      package_name: None,
+      main_entrypoint: None,
+      href_resolver,
+      usage_composer: None,
+      rewrite_map: None,
+      category_docs: None,
+      disable_search: false,
+      symbol_redirect_map: None,
+      default_symbol_map: None,
+      heading_level: 2,
+      hide_void_return_type: false,
+      declaration_view: false,
+      literal_union_list: false,
+      tag_renderers: None,
+      single_page: false,
+      optional_param_undefined: false,
+      intrinsic_type_docs_base: None,
+      hide_internal_params: false,
+      example_statuses: None,
+      import_specifier: None,
+      minify_assets: false,
+      markdown_renderer: None,
+      reexport_stubs: false,
+      awaited_return_type: false,
+      inject_head: None,
+      inject_body: None,
+      symbol_added: None,
+      new_since: None,
+      sort_overloads: false,
+      module_readmes: None,
+      experimental_modules: None,
+      experimental_banner: None,
+      collapse_param_types_over: None,
+      qualified_type_names: false,
+      escape_html: false,
+      source_path_caption: false,
+      source_path_root: None,
+      group_overloads_by_return_type: false,
+      examples_position: ExamplesPosition::default(),
+      symbol_list: false,
+      strike_deprecated_signatures: false,
+      link_summary_return_type: false,
+      compact_params: false,
+      external_links_new_tab: false,
+      never_type_note: false,
+      no_script: false,
+      csp_nonce: None,
+      sort_union_return_types: false,
+      generated_at: None,
+      inline_first_example: false,
+      canonical_base_url: None,
+      param_type_comments: false,
+      summary_length: None,
+      example_line_numbers: None,
+      merge_overloads: false,
+      anchor_id_generator: None,
+      deprecated_overloads_last: false,
+      show_private_remarks: false,
+      section_toc_threshold: None,
+      llms_txt: false,
+      renamed_symbols: None,
+    }
+  }
 }
 
 #[non_exhaustive]
@@ -273,6 +593,59 @@ pub struct GenerateCtx {
   pub disable_search: bool,
   pub symbol_redirect_map: Option<IndexMap<String, IndexMap<String, String>>>,
   pub default_symbol_map: Option<IndexMap<String, String>>,
+  pub heading_level: u8,
+  pub hide_void_return_type: bool,
+  pub declaration_view: bool,
+  pub literal_union_list: bool,
+  pub tag_renderers: Option<IndexMap<String, TagRenderer>>,
+  pub single_page: bool,
+  pub optional_param_undefined: bool,
+  pub intrinsic_type_docs_base: Option<String>,
+  pub hide_internal_params: bool,
+  pub example_statuses:
+    Option<IndexMap<String, IndexMap<usize, ExampleStatus>>>,
+  pub import_specifier: Option<String>,
+  pub minify_assets: bool,
+  pub markdown_renderer: Option<Rc<dyn jsdoc::MarkdownRenderer>>,
+  pub reexport_stubs: bool,
+  pub awaited_return_type: bool,
+  pub inject_head: Option<String>,
+  pub inject_body: Option<String>,
+  pub symbol_added: Option<IndexMap<String, String>>,
+  pub new_since: Option<String>,
+  pub sort_overloads: bool,
+  pub module_readmes: Option<IndexMap<ModuleSpecifier, String>>,
+  pub experimental_modules: Option<Vec<ModuleSpecifier>>,
+  pub experimental_banner: Option<String>,
+  pub collapse_param_types_over: Option<usize>,
+  pub qualified_type_names: bool,
+  pub escape_html: bool,
+  pub source_path_caption: bool,
+  pub source_path_root: Option<String>,
+  pub group_overloads_by_return_type: bool,
+  pub examples_position: ExamplesPosition,
+  pub symbol_list: bool,
+  pub strike_deprecated_signatures: bool,
+  pub link_summary_return_type: bool,
+  pub compact_params: bool,
+  pub external_links_new_tab: bool,
+  pub never_type_note: bool,
+  pub no_script: bool,
+  pub csp_nonce: Option<String>,
+  pub sort_union_return_types: bool,
+  pub generated_at: Option<String>,
+  pub inline_first_example: bool,
+  pub canonical_base_url: Option<String>,
+  pub param_type_comments: bool,
+  pub summary_length: Option<SummaryLength>,
+  pub example_line_numbers: Option<usize>,
+  pub merge_overloads: bool,
+  pub anchor_id_generator: Option<AnchorIdGenerator>,
+  pub deprecated_overloads_last: bool,
+  pub show_private_remarks: bool,
+  pub section_toc_threshold: Option<usize>,
+  pub llms_txt: bool,
+  pub renamed_symbols: Option<IndexMap<String, IndexMap<String, String>>>,
 }
 
 impl GenerateCtx {
@@ -367,7 +740,10 @@ impl GenerateCtx {
       package_name: options.package_name,
       common_ancestor,
       doc_nodes,
-      highlight_adapter: setup_highlighter(false),
+      highlight_adapter: comrak_adapters::HighlightAdapter {
+        copy_button: !options.no_script,
+        ..setup_highlighter(false)
+      },
       #[cfg(feature = "ammonia")]
       url_rewriter: None,
       href_resolver: options.href_resolver,
@@ -376,9 +752,61 @@ impl GenerateCtx {
       main_entrypoint,
       file_mode,
       category_docs: options.category_docs,
-      disable_search: options.disable_search,
+      disable_search: options.disable_search || options.no_script,
       symbol_redirect_map: options.symbol_redirect_map,
       default_symbol_map: options.default_symbol_map,
+      heading_level: options.heading_level.clamp(1, 6),
+      hide_void_return_type: options.hide_void_return_type,
+      declaration_view: options.declaration_view,
+      literal_union_list: options.literal_union_list,
+      tag_renderers: options.tag_renderers,
+      single_page: options.single_page,
+      optional_param_undefined: options.optional_param_undefined,
+      intrinsic_type_docs_base: options.intrinsic_type_docs_base,
+      hide_internal_params: options.hide_internal_params,
+      example_statuses: options.example_statuses,
+      import_specifier: options.import_specifier,
+      minify_assets: options.minify_assets,
+      markdown_renderer: options.markdown_renderer,
+      reexport_stubs: options.reexport_stubs,
+      awaited_return_type: options.awaited_return_type,
+      inject_head: options.inject_head,
+      inject_body: options.inject_body,
+      symbol_added: options.symbol_added,
+      new_since: options.new_since,
+      sort_overloads: options.sort_overloads,
+      module_readmes: options.module_readmes,
+      experimental_modules: options.experimental_modules,
+      experimental_banner: options.experimental_banner,
+      collapse_param_types_over: options.collapse_param_types_over,
+      qualified_type_names: options.qualified_type_names,
+      escape_html: options.escape_html,
+      source_path_caption: options.source_path_caption,
+      source_path_root: options.source_path_root,
+      group_overloads_by_return_type: options.group_overloads_by_return_type,
+      examples_position: options.examples_position,
+      symbol_list: options.symbol_list,
+      strike_deprecated_signatures: options.strike_deprecated_signatures,
+      link_summary_return_type: options.link_summary_return_type,
+      compact_params: options.compact_params,
+      external_links_new_tab: options.external_links_new_tab,
+      never_type_note: options.never_type_note,
+      no_script: options.no_script,
+      csp_nonce: options.csp_nonce,
+      sort_union_return_types: options.sort_union_return_types,
+      generated_at: options.generated_at,
+      inline_first_example: options.inline_first_example,
+      canonical_base_url: options.canonical_base_url,
+      param_type_comments: options.param_type_comments,
+      summary_length: options.summary_length,
+      example_line_numbers: options.example_line_numbers,
+      merge_overloads: options.merge_overloads,
+      anchor_id_generator: options.anchor_id_generator,
+      deprecated_overloads_last: options.deprecated_overloads_last,
+      show_private_remarks: options.show_private_remarks,
+      section_toc_threshold: options.section_toc_threshold,
+      llms_txt: options.llms_txt,
+      renamed_symbols: options.renamed_symbols,
     })
   }
 
@@ -390,23 +818,92 @@ impl GenerateCtx {
     HANDLEBARS.render(template, data).unwrap()
   }
 
+  /// Return the passed stylesheet, minified if `minify_assets` is set.
+  pub fn css_asset(&self, css: &str) -> String {
+    if self.minify_assets {
+      minify::minify_css(css)
+    } else {
+      css.to_string()
+    }
+  }
+
+  /// Return the passed script, minified if `minify_assets` is set.
+  pub fn js_asset(&self, js: &str) -> String {
+    if self.minify_assets {
+      minify::minify_js(js)
+    } else {
+      js.to_string()
+    }
+  }
+
   pub fn resolve_path(
     &self,
     current: UrlResolveKind,
     target: UrlResolveKind,
   ) -> String {
-    if let Some(symbol_redirect_map) = &self.symbol_redirect_map {
-      if let UrlResolveKind::Symbol { file, symbol } = target {
-        if let Some(path_map) = symbol_redirect_map.get(&file.path) {
-          if let Some(href) = path_map.get(symbol) {
-            return href.clone();
-          }
+    if self.single_page {
+      return match target {
+        UrlResolveKind::Symbol { file, symbol } => {
+          format!("#{}", util::single_page_symbol_id(self, file, symbol))
         }
+        UrlResolveKind::File(file) => {
+          format!("#{}", util::single_page_file_id(self, file))
+        }
+        _ => String::from("#"),
+      };
+    }
+
+    if let UrlResolveKind::Symbol { file, symbol } = target {
+      if let Some(href) = self.symbol_redirect(file, symbol) {
+        return href.to_string();
       }
     }
 
     self.href_resolver.resolve_path(current, target)
   }
+
+  /// The canonical href of the symbol as specified in `symbol_redirect_map`.
+  pub fn symbol_redirect(
+    &self,
+    file: &ShortPath,
+    symbol: &str,
+  ) -> Option<&str> {
+    self
+      .symbol_redirect_map
+      .as_ref()?
+      .get(&file.path)?
+      .get(symbol)
+      .map(|href| href.as_str())
+  }
+
+  /// The absolute canonical URL of the page of the symbol, if
+  /// `canonical_base_url` is set.
+  pub fn canonical_symbol_url(
+    &self,
+    file: &ShortPath,
+    symbol: &str,
+  ) -> Option<String> {
+    let base = self.canonical_base_url.as_ref()?;
+    let base = if base.ends_with('/') {
+      ModuleSpecifier::parse(base)
+    } else {
+      ModuleSpecifier::parse(&format!("{base}/"))
+    }
+    .ok()?;
+
+    let href = match self.symbol_redirect(file, symbol) {
+      Some(href) => href.to_string(),
+      None => format!("./{}/~/{symbol}.html", file.path),
+    };
+
+    base.join(&href).ok().map(String::from)
+  }
+
+  /// Whether the symbol should be rendered as a stub page which redirects to
+  /// its canonical definition.
+  pub fn is_reexport_stub(&self, file: &ShortPath, symbol: &str) -> bool {
+    self.reexport_stubs && self.symbol_redirect(file, symbol).is_some()
+  }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -796,9 +1293,45 @@ pub fn setup_highlighter(
   comrak_adapters::HighlightAdapter {
     language_cb: tree_sitter::tree_sitter_language_cb,
     show_line_numbers,
+    copy_button: true,
+    excerpt_start_line: None,
   }
 }
 
+/// Format a time as an ISO-8601 timestamp in UTC, eg.
+/// `2024-05-01T12:30:00Z`.
+pub fn iso_8601_timestamp(time: std::time::SystemTime) -> String {
+  let secs = time
+    .duration_since(std::time::UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_secs());
+  let (days, secs) = (secs / 86400, secs % 86400);
+
+  // convert the days since the epoch to a civil date
+  let days = days as i64 + 719468;
+  let era = days.div_euclid(146097);
+  let day_of_era = days.rem_euclid(146097);
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+    - day_of_era / 146096)
+    / 365;
+  let day_of_year =
+    day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 {
+    month_index + 3
+  } else {
+    month_index - 9
+  };
+  let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+  format!(
+    "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+    secs / 3600,
+    secs / 60 % 60,
+    secs % 60
+  )
+}
+
 #[derive(Default, Debug, Eq, PartialEq)]
 pub enum FileMode {
   #[default]
@@ -809,9 +1342,27 @@ pub enum FileMode {
 }
 
 pub fn generate(
-  mut options: GenerateOptions,
+  options: GenerateOptions,
   doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<DocNode>>,
 ) -> Result<HashMap<String, String>, anyhow::Error> {
+  let mut files = HashMap::new();
+
+  generate_streaming(options, doc_nodes_by_url, |path, content| {
+    files.insert(path, content);
+    Ok(())
+  })?;
+
+  Ok(files)
+}
+
+/// Generate the same files as [`generate`], but pass each file to `on_file`
+/// as soon as it is rendered instead of collecting them, so that the caller
+/// can eg. write each file to disk immediately.
+pub fn generate_streaming(
+  mut options: GenerateOptions,
+  doc_nodes_by_url: IndexMap<ModuleSpecifier, Vec<DocNode>>,
+  mut on_file: impl FnMut(String, String) -> Result<(), anyhow::Error>,
+) -> Result<(), anyhow::Error> {
   if doc_nodes_by_url.len() == 1 && options.main_entrypoint.is_none() {
     options.main_entrypoint =
       Some(doc_nodes_by_url.keys().next().unwrap().clone());
@@ -832,7 +1383,37 @@ pub fn generate(
   let common_ancestor = find_common_ancestor(doc_nodes_by_url.keys(), true);
   let ctx =
     GenerateCtx::new(options, common_ancestor, file_mode, doc_nodes_by_url)?;
-  let mut files = HashMap::new();
+
+  if ctx.symbol_list {
+    on_file(
+      SYMBOL_LIST_FILENAME.into(),
+      serde_json::to_string(&generate_symbol_list(&ctx))?,
+    )?;
+  }
+
+  if ctx.llms_txt {
+    on_file(LLMS_TXT_FILENAME.into(), generate_llms_txt(&ctx))?;
+  }
+
+  if ctx.single_page {
+    let single_page = pages::SinglePageCtx::new(&ctx);
+
+    on_file(
+      "./index.html".to_string(),
+      ctx.render(pages::SinglePageCtx::TEMPLATE, &single_page),
+    )?;
+    on_file(STYLESHEET_FILENAME.into(), ctx.css_asset(STYLESHEET))?;
+    on_file(
+      PAGE_STYLESHEET_FILENAME.into(),
+      ctx.css_asset(PAGE_STYLESHEET),
+    )?;
+    on_file(
+      RESET_STYLESHEET_FILENAME.into(),
+      ctx.css_asset(RESET_STYLESHEET),
+    )?;
+
+    return Ok(());
+  }
 
   // Index page
   {
@@ -866,10 +1447,10 @@ pub fn generate(
       uses_categories,
     );
 
-    files.insert(
+    on_file(
       "./index.html".to_string(),
       ctx.render(pages::IndexCtx::TEMPLATE, &index),
-    );
+    )?;
   }
 
   let all_doc_nodes = ctx
@@ -886,10 +1467,10 @@ pub fn generate(
 
     let all_symbols = pages::AllSymbolsCtx::new(&ctx, partitions_by_kind);
 
-    files.insert(
+    on_file(
       "./all_symbols.html".to_string(),
       ctx.render(pages::AllSymbolsCtx::TEMPLATE, &all_symbols),
-    );
+    )?;
   }
 
   // Category pages
@@ -907,10 +1488,10 @@ pub fn generate(
           partitions,
           &all_doc_nodes,
         );
-        files.insert(
+        on_file(
           format!("{}.html", util::slugify(category)),
           ctx.render(pages::IndexCtx::TEMPLATE, &index),
-        );
+        )?;
       }
     }
   }
@@ -925,8 +1506,16 @@ pub fn generate(
 
       let symbol_pages =
         generate_symbol_pages_for_module(&ctx, short_path, doc_nodes);
+      let mut page_names = HashSet::new();
+
+      for symbol_page in symbol_pages {
+        page_names.insert(match &symbol_page {
+          SymbolPage::Symbol {
+            symbol_group_ctx, ..
+          } => symbol_group_ctx.name.clone(),
+          SymbolPage::Redirect { current_symbol, .. } => current_symbol.clone(),
+        });
 
-      files.extend(symbol_pages.into_iter().flat_map(|symbol_page| {
         match symbol_page {
           SymbolPage::Symbol {
             breadcrumbs_ctx,
@@ -947,30 +1536,40 @@ pub fn generate(
             // contains the package name, which we already render in the head
             title_parts.pop();
 
-            let html_head_ctx = pages::HtmlHeadCtx::new(
+            let mut html_head_ctx = pages::HtmlHeadCtx::new(
               &root,
               Some(&title_parts.join(" - ")),
-              ctx.package_name.as_ref(),
+              &ctx,
               Some(short_path),
-              ctx.disable_search,
             );
+            html_head_ctx.canonical_url =
+              ctx.canonical_symbol_url(short_path, &symbol_group_ctx.name);
 
             let file_name =
               format!("{}/~/{}.html", short_path.path, symbol_group_ctx.name);
 
+            let experimental_banner = pages::render_experimental_banner(
+              &ctx,
+              short_path,
+              doc_nodes,
+              &symbol_group_ctx.name,
+            );
+
             let page_ctx = pages::SymbolPageCtx {
               html_head_ctx,
               symbol_group_ctx,
               breadcrumbs_ctx,
               toc_ctx,
               disable_search: ctx.disable_search,
+              no_script: ctx.no_script,
               categories_panel,
+              experimental_banner,
             };
 
             let symbol_page =
               ctx.render(pages::SymbolPageCtx::TEMPLATE, &page_ctx);
 
-            vec![(file_name, symbol_page)]
+            on_file(file_name, symbol_page)?;
           }
           SymbolPage::Redirect {
             current_symbol,
@@ -981,10 +1580,38 @@ pub fn generate(
             let file_name =
               format!("{}/~/{}.html", short_path.path, current_symbol);
 
-            vec![(file_name, ctx.render("pages/redirect", &redirect))]
+            on_file(file_name, ctx.render("pages/redirect", &redirect))?;
           }
         }
-      }));
+      }
+
+      let renamed_symbols = ctx
+        .renamed_symbols
+        .as_ref()
+        .and_then(|renamed_symbols| renamed_symbols.get(&short_path.path));
+
+      for (previous_name, name) in renamed_symbols.into_iter().flatten() {
+        if page_names.contains(previous_name) || !page_names.contains(name) {
+          continue;
+        }
+
+        let href = ctx.resolve_path(
+          UrlResolveKind::Symbol {
+            file: short_path,
+            symbol: previous_name,
+          },
+          UrlResolveKind::Symbol {
+            file: short_path,
+            symbol: name,
+          },
+        );
+        let redirect = serde_json::json!({ "path": href });
+
+        on_file(
+          format!("{}/~/{}.html", short_path.path, previous_name),
+          ctx.render("pages/redirect", &redirect),
+        )?;
+      }
 
       if !short_path.is_main {
         let index = pages::IndexCtx::new(
@@ -994,27 +1621,37 @@ pub fn generate(
           false,
         );
 
-        files.insert(
+        on_file(
           format!("{}/index.html", short_path.path),
           ctx.render(pages::IndexCtx::TEMPLATE, &index),
-        );
+        )?;
       }
     }
   }
 
-  files.insert(STYLESHEET_FILENAME.into(), STYLESHEET.into());
-  files.insert(
-    SEARCH_INDEX_FILENAME.into(),
-    search::get_search_index_file(&ctx)?,
-  );
-  files.insert(SCRIPT_FILENAME.into(), SCRIPT_JS.into());
+  on_file(STYLESHEET_FILENAME.into(), ctx.css_asset(STYLESHEET))?;
+  if !ctx.no_script {
+    on_file(
+      SEARCH_INDEX_FILENAME.into(),
+      ctx.js_asset(&search::get_search_index_file(&ctx)?),
+    )?;
+    on_file(SCRIPT_FILENAME.into(), ctx.js_asset(SCRIPT_JS))?;
+  }
 
-  files.insert(PAGE_STYLESHEET_FILENAME.into(), PAGE_STYLESHEET.into());
-  files.insert(RESET_STYLESHEET_FILENAME.into(), RESET_STYLESHEET.into());
-  files.insert(FUSE_FILENAME.into(), FUSE_JS.into());
-  files.insert(SEARCH_FILENAME.into(), SEARCH_JS.into());
+  on_file(
+    PAGE_STYLESHEET_FILENAME.into(),
+    ctx.css_asset(PAGE_STYLESHEET),
+  )?;
+  on_file(
+    RESET_STYLESHEET_FILENAME.into(),
+    ctx.css_asset(RESET_STYLESHEET),
+  )?;
+  if !ctx.no_script {
+    on_file(FUSE_FILENAME.into(), FUSE_JS.into())?;
+    on_file(SEARCH_FILENAME.into(), ctx.js_asset(SEARCH_JS))?;
+  }
 
-  Ok(files)
+  Ok(())
 }
 
 pub fn find_common_ancestor<'a>(
@@ -1066,6 +1703,19 @@ pub fn find_common_ancestor<'a>(
 mod test {
   use super::*;
 
+  #[test]
+  fn iso_8601() {
+    let at = |secs| {
+      iso_8601_timestamp(
+        std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs),
+      )
+    };
+
+    assert_eq!(at(0), "1970-01-01T00:00:00Z");
+    assert_eq!(at(951782400), "2000-02-29T00:00:00Z");
+    assert_eq!(at(1714566600), "2024-05-01T12:30:00Z");
+  }
+
   #[cfg(not(windows))]
   #[test]
   fn common_ancestor_root() {
@@ -1109,6 +1759,43 @@ mod test {
     );
   }
 
+  #[test]
+  fn compare_versions() {
+    use std::cmp::Ordering;
+    use util::compare_versions;
+
+    assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+    assert_eq!(compare_versions("v1.2.0", "1.2.0"), Ordering::Equal);
+    assert_eq!(compare_versions("1.2", "1.2.1"), Ordering::Less);
+    assert_eq!(
+      compare_versions("2024-05-01", "2024-04-30"),
+      Ordering::Greater
+    );
+  }
+
+  #[test]
+  fn tag_order() {
+    use util::Tag;
+
+    let mut tags = indexmap::IndexSet::from([
+      Tag::Other("internal".into()),
+      Tag::New,
+      Tag::Optional,
+      Tag::Deprecated,
+    ]);
+    tags.sort();
+
+    assert_eq!(
+      tags.into_iter().collect::<Vec<_>>(),
+      [
+        Tag::New,
+        Tag::Deprecated,
+        Tag::Optional,
+        Tag::Other("internal".into())
+      ]
+    );
+  }
+
   #[track_caller]
   fn run_common_ancestor_test(
     specifiers: &[&str],
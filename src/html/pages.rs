@@ -11,6 +11,7 @@ use super::RenderContext;
 use super::ShortPath;
 use super::SymbolGroupCtx;
 use super::UrlResolveKind;
+use std::collections::HashSet;
 use std::rc::Rc;
 
 use super::FUSE_FILENAME;
@@ -25,6 +26,7 @@ use crate::html::usage::UsagesCtx;
 use crate::js_doc::JsDocTag;
 use crate::DocNodeKind;
 use indexmap::IndexMap;
+use indexmap::IndexSet;
 use serde::Serialize;
 
 #[derive(Debug, Serialize, Clone)]
@@ -39,6 +41,11 @@ pub struct HtmlHeadCtx {
   fuse_js: String,
   url_search: String,
   disable_search: bool,
+  nonce: Option<String>,
+  generated_at: Option<String>,
+  pub canonical_url: Option<String>,
+  inject_head: Option<String>,
+  inject_body: Option<String>,
 }
 
 impl HtmlHeadCtx {
@@ -47,15 +54,16 @@ impl HtmlHeadCtx {
   pub fn new(
     root: &str,
     page: Option<&str>,
-    package_name: Option<&String>,
+    ctx: &GenerateCtx,
     current_file: Option<&ShortPath>,
-    disable_search: bool,
   ) -> Self {
     Self {
       title: format!(
         "{}{}documentation",
         page.map(|page| format!("{page} - ")).unwrap_or_default(),
-        package_name
+        ctx
+          .package_name
+          .as_ref()
           .map(|package_name| format!("{package_name} "))
           .unwrap_or_default()
       ),
@@ -70,7 +78,12 @@ impl HtmlHeadCtx {
       script_js: format!("{root}{SCRIPT_FILENAME}"),
       fuse_js: format!("{root}{FUSE_FILENAME}"),
       url_search: format!("{root}{SEARCH_FILENAME}"),
-      disable_search,
+      disable_search: ctx.disable_search,
+      nonce: ctx.csp_nonce.clone(),
+      generated_at: ctx.generated_at.clone(),
+      canonical_url: None,
+      inject_head: ctx.inject_head.clone(),
+      inject_body: ctx.inject_body.clone(),
     }
   }
 }
@@ -229,9 +242,8 @@ impl IndexCtx {
           Some(short_path.display_name())
         }
       }),
-      ctx.package_name.as_ref(),
+      ctx,
       None,
-      ctx.disable_search,
     );
 
     let overview = match ctx.file_mode {
@@ -267,6 +279,7 @@ impl IndexCtx {
                 doc,
               },
               content: util::SectionContentCtx::Empty,
+              heading_level: ctx.heading_level,
             }
           })
           .collect::<Vec<_>>();
@@ -299,6 +312,7 @@ impl IndexCtx {
                   super::jsdoc::MarkdownToHTMLOptions {
                     title_only: false,
                     no_toc: false,
+                    line_numbers_start: None,
                   },
                 )
               });
@@ -314,6 +328,7 @@ impl IndexCtx {
                 doc,
               },
               content: util::SectionContentCtx::Empty,
+              heading_level: ctx.heading_level,
             }
           })
           .collect::<Vec<_>>();
@@ -388,13 +403,7 @@ impl IndexCtx {
     let root =
       ctx.resolve_path(UrlResolveKind::Category(name), UrlResolveKind::Root);
 
-    let html_head_ctx = HtmlHeadCtx::new(
-      &root,
-      Some(name),
-      ctx.package_name.as_ref(),
-      None,
-      ctx.disable_search,
-    );
+    let html_head_ctx = HtmlHeadCtx::new(&root, Some(name), ctx, None);
 
     let breadcrumbs_ctx = render_ctx.get_breadcrumbs();
 
@@ -452,13 +461,7 @@ impl AllSymbolsCtx {
       }),
     );
 
-    let html_head_ctx = HtmlHeadCtx::new(
-      "./",
-      Some("All Symbols"),
-      ctx.package_name.as_ref(),
-      None,
-      ctx.disable_search,
-    );
+    let html_head_ctx = HtmlHeadCtx::new("./", Some("All Symbols"), ctx, None);
 
     let categories_panel = CategoriesPanelCtx::new(&render_ctx, None);
 
@@ -489,16 +492,22 @@ pub enum SymbolPage {
   },
 }
 
-pub fn generate_symbol_pages_for_module(
-  ctx: &GenerateCtx,
-  short_path: &ShortPath,
-  module_doc_nodes: &[DocNodeWithContext],
-) -> Vec<SymbolPage> {
+/// Lazily render the pages of the symbols of a module, so that each page can
+/// be handled before the next one is rendered.
+pub fn generate_symbol_pages_for_module<'ctx>(
+  ctx: &'ctx GenerateCtx,
+  short_path: &'ctx ShortPath,
+  module_doc_nodes: &'ctx [DocNodeWithContext],
+) -> impl Iterator<Item = SymbolPage> + 'ctx {
   let mut name_partitions =
     partition::partition_nodes_by_name(module_doc_nodes, true);
 
   let mut drilldown_partitions = IndexMap::new();
-  for doc_nodes in name_partitions.values() {
+  for (name, doc_nodes) in &name_partitions {
+    if ctx.is_reexport_stub(short_path, name) {
+      continue;
+    }
+
     for doc_node in doc_nodes {
       if let Some(drilldown_symbols) = doc_node.get_drilldown_symbols() {
         drilldown_partitions.extend(partition::partition_nodes_by_name(
@@ -510,44 +519,53 @@ pub fn generate_symbol_pages_for_module(
   }
   name_partitions.extend(drilldown_partitions);
 
-  let mut generated_pages = Vec::with_capacity(name_partitions.values().len());
-
   let render_ctx =
     RenderContext::new(ctx, module_doc_nodes, UrlResolveKind::File(short_path));
 
-  for (name, doc_nodes) in name_partitions {
-    let (breadcrumbs_ctx, symbol_group_ctx, toc_ctx, categories_panel) =
-      render_symbol_page(&render_ctx, short_path, &name, &doc_nodes);
+  name_partitions
+    .into_iter()
+    .flat_map(move |(name, doc_nodes)| {
+      if ctx.reexport_stubs {
+        if let Some(href) = ctx.symbol_redirect(short_path, &name) {
+          return vec![SymbolPage::Redirect {
+            href: href.to_string(),
+            current_symbol: name,
+          }];
+        }
+      }
 
-    generated_pages.push(SymbolPage::Symbol {
-      breadcrumbs_ctx,
-      symbol_group_ctx,
-      toc_ctx,
-      categories_panel,
-    });
+      let (breadcrumbs_ctx, symbol_group_ctx, toc_ctx, categories_panel) =
+        render_symbol_page(&render_ctx, short_path, &name, &doc_nodes);
 
-    if doc_nodes
-      .iter()
-      .any(|doc_node| doc_node.kind() == DocNodeKind::Class)
-    {
-      let prototype_name = format!("{name}.prototype");
-      generated_pages.push(SymbolPage::Redirect {
-        href: ctx.resolve_path(
-          UrlResolveKind::Symbol {
-            file: short_path,
-            symbol: &prototype_name,
-          },
-          UrlResolveKind::Symbol {
-            file: short_path,
-            symbol: &name,
-          },
-        ),
-        current_symbol: prototype_name,
-      });
-    }
-  }
+      let mut pages = vec![SymbolPage::Symbol {
+        breadcrumbs_ctx,
+        symbol_group_ctx,
+        toc_ctx,
+        categories_panel,
+      }];
+
+      if doc_nodes
+        .iter()
+        .any(|doc_node| doc_node.kind() == DocNodeKind::Class)
+      {
+        let prototype_name = format!("{name}.prototype");
+        pages.push(SymbolPage::Redirect {
+          href: ctx.resolve_path(
+            UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &prototype_name,
+            },
+            UrlResolveKind::Symbol {
+              file: short_path,
+              symbol: &name,
+            },
+          ),
+          current_symbol: prototype_name,
+        });
+      }
 
-  generated_pages
+      pages
+    })
 }
 
 #[derive(Debug, Serialize)]
@@ -557,13 +575,63 @@ pub struct SymbolPageCtx {
   pub breadcrumbs_ctx: BreadcrumbsCtx,
   pub toc_ctx: util::ToCCtx,
   pub disable_search: bool,
+  pub no_script: bool,
   pub categories_panel: Option<CategoriesPanelCtx>,
+  pub experimental_banner: Option<String>,
 }
 
 impl SymbolPageCtx {
   pub const TEMPLATE: &'static str = "pages/symbol";
 }
 
+const DEFAULT_EXPERIMENTAL_BANNER: &str =
+  "This module is experimental. Its API may change or be removed in a future release.";
+
+/// Render the disclaimer banner shown on the symbol pages of the module, if
+/// it is experimental.
+pub(crate) fn render_experimental_banner(
+  ctx: &GenerateCtx,
+  short_path: &ShortPath,
+  doc_nodes: &[DocNodeWithContext],
+  symbol: &str,
+) -> Option<String> {
+  let flagged = ctx
+    .experimental_modules
+    .as_ref()
+    .is_some_and(|modules| modules.contains(&short_path.specifier));
+
+  let tagged = doc_nodes.iter().any(|doc_node| {
+    doc_node.kind() == DocNodeKind::ModuleDoc
+      && doc_node
+        .js_doc
+        .tags
+        .iter()
+        .any(|tag| matches!(tag, JsDocTag::Experimental))
+  });
+
+  if !flagged && !tagged {
+    return None;
+  }
+
+  let render_ctx = RenderContext::new(
+    ctx,
+    doc_nodes,
+    UrlResolveKind::Symbol {
+      file: short_path,
+      symbol,
+    },
+  );
+
+  Some(super::jsdoc::render_markdown(
+    &render_ctx,
+    ctx
+      .experimental_banner
+      .as_deref()
+      .unwrap_or(DEFAULT_EXPERIMENTAL_BANNER),
+    true,
+  ))
+}
+
 pub fn render_symbol_page(
   render_ctx: &RenderContext,
   short_path: &ShortPath,
@@ -627,3 +695,123 @@ pub fn render_symbol_page(
     categories_panel,
   )
 }
+
+lazy_static! {
+  static ref SINGLE_PAGE_ANCHOR_RE: regex::Regex =
+    regex::Regex::new(r##"\b(id|for|href)="(#?)([^"#]+)""##).unwrap();
+}
+
+#[derive(Debug, Serialize)]
+pub struct SinglePageSymbolCtx {
+  pub id: String,
+  pub name: String,
+  pub kind: Vec<util::DocNodeKindCtx>,
+  pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SinglePageModuleCtx {
+  pub id: String,
+  pub name: String,
+  pub symbols: Vec<SinglePageSymbolCtx>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SinglePageCtx {
+  pub html_head_ctx: HtmlHeadCtx,
+  pub modules: Vec<SinglePageModuleCtx>,
+}
+
+impl SinglePageCtx {
+  pub const TEMPLATE: &'static str = "pages/single_page";
+
+  pub fn new(ctx: &GenerateCtx) -> Self {
+    let module_ids = ctx
+      .doc_nodes
+      .keys()
+      .map(|short_path| util::single_page_file_id(ctx, short_path))
+      .collect::<HashSet<_>>();
+
+    let modules = ctx
+      .doc_nodes
+      .iter()
+      .map(|(short_path, module_doc_nodes)| {
+        let render_ctx = RenderContext::new(
+          ctx,
+          module_doc_nodes,
+          UrlResolveKind::File(short_path),
+        );
+
+        let symbols =
+          partition::partition_nodes_by_name(module_doc_nodes, true)
+            .into_iter()
+            .map(|(name, doc_nodes)| {
+              let (_, symbol_group_ctx, _, _) =
+                render_symbol_page(&render_ctx, short_path, &name, &doc_nodes);
+              let id = util::single_page_symbol_id(ctx, short_path, &name);
+
+              let content =
+                ctx.render(SymbolGroupCtx::TEMPLATE, &symbol_group_ctx);
+
+              SinglePageSymbolCtx {
+                content: prefix_anchors(&content, &id, &module_ids),
+                kind: doc_nodes
+                  .iter()
+                  .map(|doc_node| doc_node.kind_with_drilldown.into())
+                  .collect::<IndexSet<_>>()
+                  .into_iter()
+                  .collect(),
+                id,
+                name,
+              }
+            })
+            .collect();
+
+        SinglePageModuleCtx {
+          id: util::single_page_file_id(ctx, short_path),
+          name: short_path.display_name().to_string(),
+          symbols,
+        }
+      })
+      .collect();
+
+    // all symbols are already on the page, so there is nothing to search for
+    let mut html_head_ctx = HtmlHeadCtx::new("./", None, ctx, None);
+    html_head_ctx.disable_search = true;
+
+    Self {
+      html_head_ctx,
+      modules,
+    }
+  }
+}
+
+/// Prefix all ids of a rendered symbol and the in-page links pointing to them
+/// with the id of the symbol, so that they don't collide with the ids of the
+/// other symbols on the same page. Links to other symbols, whose ids start
+/// with one of the `module_ids`, are left untouched.
+fn prefix_anchors(
+  html: &str,
+  prefix: &str,
+  module_ids: &HashSet<String>,
+) -> String {
+  SINGLE_PAGE_ANCHOR_RE
+    .replace_all(html, |captures: &regex::Captures| {
+      let attr = &captures[1];
+      let hash = &captures[2];
+      let value = &captures[3];
+
+      let is_symbol_link = module_ids.iter().any(|module_id| {
+        value
+          .strip_prefix(module_id.as_str())
+          .is_some_and(|rest| rest.is_empty() || rest.starts_with("__"))
+      });
+
+      if (attr == "href" && hash.is_empty()) || is_symbol_link {
+        captures[0].to_string()
+      } else {
+        format!(r#"{attr}="{hash}{prefix}__{value}""#)
+      }
+    })
+    .into_owned()
+}
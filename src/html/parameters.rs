@@ -1,24 +1,182 @@
 use super::render_context::RenderContext;
 use super::types::render_type_def_colon;
+use crate::class::ClassConstructorParamDef;
+use crate::params::ObjectPatPropDef;
 use crate::params::ParamDef;
 use crate::params::ParamPatternDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use deno_ast::swc::ast::Accessibility;
+use std::borrow::Cow;
 
 pub(crate) fn render_params(
   ctx: &RenderContext,
   params: &[ParamDef],
 ) -> String {
-  if params.is_empty() {
-    String::new()
-  } else if params.len() == 1 {
-    format!("<span>{}</span>", render_param(ctx, &params[0], 0))
-  } else {
-    let mut items = Vec::with_capacity(params.len());
+  join_params(
+    params
+      .iter()
+      .enumerate()
+      .map(|(i, def)| render_param(ctx, def, i))
+      .collect(),
+  )
+}
+
+/// Expands a trailing rest parameter typed as a tuple, eg.
+/// `...args: [string, number?]`, into a parameter for each element, eg.
+/// `args[0]: string, args[1]?: number`, so that the spread is not mistaken
+/// for a single parameter. Tuple type aliases are resolved if documented.
+pub(crate) fn expand_tuple_rest<'a>(
+  ctx: &RenderContext,
+  params: &'a [ParamDef],
+) -> Cow<'a, [ParamDef]> {
+  let Some((
+    ParamDef {
+      pattern: ParamPatternDef::Rest { arg },
+      ts_type: Some(ts_type),
+      ..
+    },
+    leading,
+  )) = params.split_last()
+  else {
+    return Cow::Borrowed(params);
+  };
+
+  let ParamPatternDef::Identifier { name, .. } = &arg.pattern else {
+    return Cow::Borrowed(params);
+  };
+
+  let Some(elements) = tuple_elements(ctx, ts_type) else {
+    return Cow::Borrowed(params);
+  };
+
+  let mut expanded = leading.to_vec();
+
+  for (i, element) in elements.iter().enumerate() {
+    let element_name = format!("{name}[{i}]");
+
+    let (pattern, ts_type) = match element.kind {
+      Some(TsTypeDefKind::Optional) => (
+        ParamPatternDef::Identifier {
+          name: element_name,
+          optional: true,
+        },
+        element.optional.as_deref(),
+      ),
+      Some(TsTypeDefKind::Rest) => (
+        ParamPatternDef::Rest {
+          arg: Box::new(ParamDef {
+            pattern: ParamPatternDef::Identifier {
+              name: element_name,
+              optional: false,
+            },
+            decorators: Box::new([]),
+            ts_type: None,
+            type_comment: None,
+          }),
+        },
+        element.rest.as_deref(),
+      ),
+      _ => (
+        ParamPatternDef::Identifier {
+          name: element_name,
+          optional: false,
+        },
+        Some(element),
+      ),
+    };
+
+    expanded.push(ParamDef {
+      pattern,
+      decorators: Box::new([]),
+      ts_type: ts_type.cloned(),
+      type_comment: None,
+    });
+  }
+
+  Cow::Owned(expanded)
+}
+
+/// The elements of a tuple type, or of the documented type alias without
+/// type parameters it refers to.
+fn tuple_elements<'a>(
+  ctx: &'a RenderContext,
+  ts_type: &'a TsTypeDef,
+) -> Option<&'a [TsTypeDef]> {
+  match ts_type.kind {
+    Some(TsTypeDefKind::Tuple) => ts_type.tuple.as_deref(),
+    Some(TsTypeDefKind::TypeRef) => {
+      let type_ref = ts_type.type_ref.as_ref()?;
+      if type_ref.type_params.is_some() {
+        return None;
+      }
+
+      let type_alias_def = ctx
+        .ctx
+        .doc_nodes
+        .values()
+        .flatten()
+        .filter(|node| node.get_qualified_name() == type_ref.type_name)
+        .find_map(|node| node.type_alias_def())
+        .filter(|type_alias_def| type_alias_def.type_params.is_empty())?;
 
-    for (i, def) in params.iter().enumerate() {
-      items.push(format!("<div>{},</div>", render_param(ctx, def, i)));
+      type_alias_def
+        .ts_type
+        .tuple
+        .as_deref()
+        .filter(|_| type_alias_def.ts_type.kind == Some(TsTypeDefKind::Tuple))
     }
+    _ => None,
+  }
+}
+
+/// Renders the parameters of a constructor, including the modifiers of
+/// parameter properties, eg. `private readonly x: number`.
+pub(crate) fn render_constructor_params(
+  ctx: &RenderContext,
+  params: &[ClassConstructorParamDef],
+) -> String {
+  join_params(
+    params
+      .iter()
+      .enumerate()
+      .map(|(i, def)| {
+        let mut modifiers = String::new();
+        match def.accessibility {
+          Some(Accessibility::Public) => modifiers.push_str("public "),
+          Some(Accessibility::Protected) => modifiers.push_str("protected "),
+          Some(Accessibility::Private) => modifiers.push_str("private "),
+          None => {}
+        }
+        if def.is_override {
+          modifiers.push_str("override ");
+        }
+        if def.readonly {
+          modifiers.push_str("readonly ");
+        }
 
-    let content = items.join("");
+        let param = render_param(ctx, &def.param, i);
+
+        if modifiers.is_empty() {
+          param
+        } else {
+          format!("<span><span>{modifiers}</span>{param}</span>")
+        }
+      })
+      .collect(),
+  )
+}
+
+fn join_params(items: Vec<String>) -> String {
+  if items.is_empty() {
+    String::new()
+  } else if items.len() == 1 {
+    format!("<span>{}</span>", items[0])
+  } else {
+    let mut content = String::new();
+    for item in items {
+      content.push_str(&format!("<div>{item},</div>"));
+    }
 
     format!(r#"<div class="ml-4">{content}</div>"#)
   }
@@ -50,7 +208,7 @@ fn render_param(ctx: &RenderContext, param: &ParamDef, i: usize) -> String {
 pub(crate) fn param_name(param: &ParamDef, i: usize) -> (String, String) {
   match &param.pattern {
     ParamPatternDef::Array { .. } | ParamPatternDef::Object { .. } => (
-      format!(r#"<span class="italic">unnamed {i}</span>"#),
+      html_escape::encode_text(&pattern_bindings(&param.pattern)).into_owned(),
       format!(r#"(unnamed {i})"#),
     ),
     ParamPatternDef::Assign { left, .. } => param_name(left, i),
@@ -63,3 +221,52 @@ pub(crate) fn param_name(param: &ParamDef, i: usize) -> (String, String) {
     ),
   }
 }
+
+/// Renders the names bound by a parameter pattern, eg. `{ a, b: c }` for an
+/// object destructuring pattern.
+fn pattern_bindings(pattern: &ParamPatternDef) -> String {
+  match pattern {
+    ParamPatternDef::Array { elements, .. } => format!(
+      "[{}]",
+      elements
+        .iter()
+        .map(|element| {
+          element
+            .as_ref()
+            .map(|element| pattern_bindings(&element.pattern))
+            .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+    ParamPatternDef::Assign { left, .. } => pattern_bindings(&left.pattern),
+    ParamPatternDef::Identifier { name, .. } => name.clone(),
+    ParamPatternDef::Object { props, .. } if props.is_empty() => {
+      String::from("{}")
+    }
+    ParamPatternDef::Object { props, .. } => format!(
+      "{{ {} }}",
+      props
+        .iter()
+        .map(|prop| match prop {
+          ObjectPatPropDef::Assign { key, .. } => key.clone(),
+          ObjectPatPropDef::KeyValue { key, value } => {
+            let binding = pattern_bindings(&value.pattern);
+            if &binding == key {
+              binding
+            } else {
+              format!("{key}: {binding}")
+            }
+          }
+          ObjectPatPropDef::Rest { arg } => {
+            format!("...{}", pattern_bindings(&arg.pattern))
+          }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+    ParamPatternDef::Rest { arg } => {
+      format!("...{}", pattern_bindings(&arg.pattern))
+    }
+  }
+}
@@ -3,6 +3,7 @@ use crate::html::util::BreadcrumbsCtx;
 use crate::html::util::NamespacedSymbols;
 use crate::html::DocNodeWithContext;
 use crate::html::GenerateCtx;
+use crate::html::ShortPath;
 use crate::html::UrlResolveKind;
 use crate::node::DocNodeDef;
 use deno_graph::ModuleSpecifier;
@@ -86,6 +87,72 @@ impl<'ctx> RenderContext<'ctx> {
     self.current_resolve
   }
 
+  /// The name of the symbol qualified with the module it is resolved from,
+  /// eg. `pkg/mod.Foo`. Global symbols have no qualified name.
+  pub fn lookup_symbol_qualified_name(
+    &self,
+    target_symbol: &str,
+  ) -> Option<String> {
+    let target_symbol_parts = target_symbol
+      .split('.')
+      .map(String::from)
+      .collect::<Vec<_>>();
+
+    let qualify = |file: Option<&ShortPath>, symbol: &str| {
+      let file = file.or(self.ctx.main_entrypoint.as_deref())?;
+
+      let module = match (&self.ctx.package_name, file.is_main) {
+        (Some(package_name), true) => package_name.clone(),
+        (Some(package_name), false) => {
+          format!("{package_name}/{}", file.display_name())
+        }
+        (None, _) => file.display_name().to_string(),
+      };
+
+      Some(format!("{module}.{symbol}"))
+    };
+
+    let mut parts = self.namespace_parts.to_vec();
+    while !parts.is_empty() {
+      let mut current_parts = parts.clone();
+      current_parts.extend_from_slice(&target_symbol_parts);
+
+      if let Some(origin) = self.scoped_symbols.get(&current_parts) {
+        return qualify(
+          self.get_current_resolve().get_file().or(origin.as_deref()),
+          &current_parts.join("."),
+        );
+      }
+
+      parts.pop();
+    }
+
+    if let Some(origin) = self.scoped_symbols.get(&target_symbol_parts) {
+      return qualify(
+        self.get_current_resolve().get_file().or(origin.as_deref()),
+        target_symbol,
+      );
+    }
+
+    let src = self.current_imports.get(target_symbol)?;
+
+    if let Some(short_path) =
+      ModuleSpecifier::parse(src)
+        .ok()
+        .and_then(|module_specifier| {
+          self
+            .ctx
+            .doc_nodes
+            .keys()
+            .find(|short_path| short_path.specifier == module_specifier)
+        })
+    {
+      return qualify(Some(short_path), target_symbol);
+    }
+
+    Some(format!("{src}.{target_symbol}"))
+  }
+
   pub fn lookup_symbol_href(&self, target_symbol: &str) -> Option<String> {
     let target_symbol_parts = target_symbol
       .split('.')
@@ -167,6 +234,48 @@ impl<'ctx> RenderContext<'ctx> {
       .resolve_global_symbol(&target_symbol_parts)
   }
 
+  /// Look up the href of the symbol referenced by an import type, eg.
+  /// `import("./mod.ts").Foo`. The specifier is resolved against the current
+  /// file, falling back to the href resolver for modules which are not
+  /// documented.
+  pub fn lookup_import_type_href(
+    &self,
+    specifier: &str,
+    qualifier: &str,
+  ) -> Option<String> {
+    let module_specifier = match self.get_current_resolve().get_file() {
+      Some(file) => file.specifier.join(specifier).ok(),
+      None => ModuleSpecifier::parse(specifier).ok(),
+    };
+
+    let root_symbol = qualifier.split('.').next().unwrap_or(qualifier);
+
+    if let Some((short_path, _)) = module_specifier.and_then(|specifier| {
+      self.ctx.doc_nodes.iter().find(|(short_path, doc_nodes)| {
+        short_path.specifier == specifier
+          && doc_nodes
+            .iter()
+            .any(|doc_node| doc_node.get_name() == root_symbol)
+      })
+    }) {
+      return Some(self.ctx.resolve_path(
+        self.get_current_resolve(),
+        UrlResolveKind::Symbol {
+          file: short_path,
+          symbol: qualifier,
+        },
+      ));
+    }
+
+    let qualifier_parts =
+      qualifier.split('.').map(String::from).collect::<Vec<_>>();
+
+    self
+      .ctx
+      .href_resolver
+      .resolve_import_href(&qualifier_parts, specifier)
+  }
+
   pub fn get_breadcrumbs(&self) -> BreadcrumbsCtx {
     let index_name =
       self.ctx.package_name.clone().unwrap_or("index".to_string());
@@ -438,17 +547,7 @@ mod test {
     )]);
 
     let ctx = GenerateCtx::new(
-      GenerateOptions {
-        package_name: None,
-        main_entrypoint: None,
-        href_resolver: Rc::new(TestResolver()),
-        usage_composer: None,
-        rewrite_map: None,
-        category_docs: None,
-        disable_search: false,
-        symbol_redirect_map: None,
-        default_symbol_map: None,
-      },
+      GenerateOptions::new(Rc::new(TestResolver())),
       None,
       Default::default(),
       doc_nodes_by_url,
@@ -468,5 +567,85 @@ mod test {
     let render_ctx =
       RenderContext::new(&ctx, doc_nodes, UrlResolveKind::File(short_path));
     assert_eq!(render_ctx.lookup_symbol_href("foo").unwrap(), "b/foo");
+
+    // import types
+    assert_eq!(
+      render_ctx
+        .lookup_import_type_href("./mod.ts", "foo")
+        .unwrap(),
+      "../.././/mod.ts/~/foo.html"
+    );
+    assert_eq!(
+      render_ctx
+        .lookup_import_type_href("./other.ts", "Foo")
+        .unwrap(),
+      "./other.ts/Foo"
+    );
+
+    // qualified names
+    assert_eq!(render_ctx.lookup_symbol_qualified_name("bar"), None);
+    assert_eq!(
+      render_ctx.lookup_symbol_qualified_name("foo").unwrap(),
+      "b.foo"
+    );
+  }
+
+  #[test]
+  fn breadcrumbs() {
+    let doc_nodes_by_url = indexmap::IndexMap::from([
+      (ModuleSpecifier::parse("file:///mod.ts").unwrap(), vec![]),
+      (ModuleSpecifier::parse("file:///foo.ts").unwrap(), vec![]),
+    ]);
+
+    let ctx = GenerateCtx::new(
+      GenerateOptions {
+        main_entrypoint: Some(
+          ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+        ),
+        ..GenerateOptions::new(Rc::new(TestResolver()))
+      },
+      Some(std::path::PathBuf::from("/")),
+      Default::default(),
+      doc_nodes_by_url,
+    )
+    .unwrap();
+
+    let mut short_paths = ctx.doc_nodes.keys();
+    let main = short_paths.next().unwrap();
+    let foo = short_paths.next().unwrap();
+
+    let render_ctx = RenderContext::new(
+      &ctx,
+      &[],
+      UrlResolveKind::Symbol {
+        file: main,
+        symbol: "Foo",
+      },
+    );
+    assert_eq!(render_ctx.get_breadcrumbs().to_strings(), ["index", "Foo"]);
+
+    let render_ctx = RenderContext::new(
+      &ctx,
+      &[],
+      UrlResolveKind::Symbol {
+        file: foo,
+        symbol: "Ns.Bar",
+      },
+    );
+    let breadcrumbs = render_ctx.get_breadcrumbs();
+    assert_eq!(breadcrumbs.to_strings(), ["index", "foo.ts", "Ns.Bar"]);
+    assert_eq!(
+      breadcrumbs
+        .parts
+        .iter()
+        .map(|part| part.href.as_str())
+        .collect::<Vec<_>>(),
+      [
+        "../../",
+        "../.././foo.ts/index.html",
+        "../.././foo.ts/~/Ns.html",
+        "../.././foo.ts/~/Ns.Bar.html",
+      ]
+    );
   }
 }
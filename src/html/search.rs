@@ -109,6 +109,7 @@ pub fn generate_search_index(ctx: &GenerateCtx) -> serde_json::Value {
 
   let mut doc_nodes = partitions
     .into_iter()
+    .filter(|(name, nodes)| !ctx.is_reexport_stub(&nodes[0].origin, name))
     .flat_map(|(name, nodes)| {
       doc_nodes_into_search_index_node(ctx, nodes, name)
     })
@@ -123,6 +124,132 @@ pub fn generate_search_index(ctx: &GenerateCtx) -> serde_json::Value {
   search_index
 }
 
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SymbolListEntry {
+  name: String,
+  kind: crate::DocNodeKind,
+  file: String,
+  signature: Option<String>,
+  description: Option<String>,
+  url: String,
+}
+
+/// Generate a flat list of all symbols with their kind, signature and a one
+/// line description, eg. to be ingested by search tools. Unlike the search
+/// index, all fields are plain text.
+pub fn generate_symbol_list(ctx: &GenerateCtx) -> serde_json::Value {
+  json!({
+    "symbols": symbol_list_entries(ctx)
+  })
+}
+
+/// Generate an `llms.txt` summary of the public API, following the
+/// convention of a heading with the package name and, for each module, a
+/// list of links to its symbols with their description and signature.
+pub fn generate_llms_txt(ctx: &GenerateCtx) -> String {
+  let mut out = format!(
+    "# {}\n",
+    ctx.package_name.as_deref().unwrap_or("Documentation")
+  );
+  let mut current_file = None;
+
+  for entry in symbol_list_entries(ctx) {
+    if current_file.as_ref() != Some(&entry.file) {
+      let display_name = ctx
+        .doc_nodes
+        .keys()
+        .find(|short_path| short_path.path == entry.file)
+        .map_or(entry.file.as_str(), |short_path| short_path.display_name());
+
+      out.push_str(&format!("\n## {display_name}\n\n"));
+      current_file = Some(entry.file.clone());
+    }
+
+    let kind = serde_json::to_value(entry.kind)
+      .ok()
+      .and_then(|kind| kind.as_str().map(str::to_string))
+      .unwrap_or_default();
+
+    out.push_str(&format!("- [{}]({}): ", entry.name, entry.url));
+
+    if let Some(description) = &entry.description {
+      out.push_str(description);
+      out.push(' ');
+    }
+
+    out.push_str(&format!(
+      "`{kind} {}{}`\n",
+      entry.name,
+      entry.signature.as_deref().unwrap_or_default()
+    ));
+  }
+
+  out
+}
+
+/// The entries of the symbol list, sorted by file and name.
+fn symbol_list_entries(ctx: &GenerateCtx) -> Vec<SymbolListEntry> {
+  let doc_nodes = ctx
+    .doc_nodes
+    .values()
+    .flatten()
+    .cloned()
+    .collect::<Vec<_>>();
+  let partitions = super::partition::partition_nodes_by_name(&doc_nodes, true);
+  let render_ctx = super::RenderContext::new(
+    ctx,
+    &doc_nodes,
+    super::UrlResolveKind::AllSymbols,
+  );
+
+  let mut symbols = partitions
+    .into_iter()
+    .filter(|(name, nodes)| !ctx.is_reexport_stub(&nodes[0].origin, name))
+    .map(|(name, nodes)| {
+      let signature = nodes
+        .iter()
+        .filter_map(|node| node.function_def())
+        .find(|function_def| !function_def.has_body || nodes.len() == 1)
+        .map(super::declaration::function_signature);
+
+      let description = nodes[0]
+        .js_doc
+        .doc
+        .as_deref()
+        .and_then(|doc| doc.trim().split("\n\n").next())
+        .map(|doc| {
+          super::jsdoc::strip(&render_ctx, doc)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+        })
+        .filter(|doc| !doc.is_empty());
+
+      let url = ctx.resolve_path(
+        super::UrlResolveKind::Root,
+        super::UrlResolveKind::Symbol {
+          file: &nodes[0].origin,
+          symbol: &name,
+        },
+      );
+
+      SymbolListEntry {
+        kind: nodes[0].kind(),
+        file: nodes[0].origin.path.clone(),
+        name,
+        signature,
+        description,
+        url,
+      }
+    })
+    .collect::<Vec<_>>();
+
+  symbols.sort_by(|a, b| a.file.cmp(&b.file).then_with(|| a.name.cmp(&b.name)));
+
+  symbols
+}
+
 pub(crate) fn get_search_index_file(
   ctx: &GenerateCtx,
 ) -> Result<String, anyhow::Error> {
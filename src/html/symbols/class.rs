@@ -1,15 +1,26 @@
+use crate::class::ClassConstructorDef;
 use crate::class::ClassMethodDef;
 use crate::class::ClassPropertyDef;
-use crate::html::parameters::render_params;
+use crate::function::FunctionDef;
+use crate::html::parameters::render_constructor_params;
 use crate::html::render_context::RenderContext;
-use crate::html::types::render_type_def_colon;
+use crate::html::symbols::function::FunctionCtx;
+use crate::html::types::render_entry_type_def_colon;
+use crate::html::types::render_type_def;
 use crate::html::util::*;
 use crate::html::DocNodeWithContext;
+use crate::node::DocNode;
+use crate::node::DocNodeDef;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
+use crate::ts_type::TsTypeRefDef;
 use deno_ast::swc::ast::Accessibility;
 use deno_ast::swc::ast::MethodKind;
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::rc::Rc;
 
 pub(crate) fn render_class(
   ctx: &RenderContext,
@@ -33,9 +44,12 @@ pub(crate) fn render_class(
 
   let mut sections = vec![];
 
-  if let Some(constructors) =
-    render_constructors(ctx, &class_def.constructors, doc_node.get_name())
-  {
+  if let Some(constructors) = render_constructors(
+    ctx,
+    doc_node,
+    &class_def.constructors,
+    doc_node.get_name(),
+  ) {
     sections.push(constructors);
   }
 
@@ -107,6 +121,7 @@ pub(crate) fn render_class(
 
 fn render_constructors(
   ctx: &RenderContext,
+  doc_node: &DocNodeWithContext,
   constructors: &[crate::class::ClassConstructorDef],
   name: &str,
 ) -> Option<SectionCtx> {
@@ -114,19 +129,36 @@ fn render_constructors(
     return None;
   }
 
+  // overloaded constructors are rendered like the overloads of a function
+  if constructors.iter().filter(|c| !c.has_body).count() > 1 {
+    let constructor_nodes = constructors
+      .iter()
+      .map(|constructor| {
+        doc_node.create_child(Rc::new(constructor_to_doc_node(
+          doc_node,
+          constructor,
+          name,
+        )))
+      })
+      .collect::<Vec<_>>();
+
+    let mut section =
+      SectionCtx::new(ctx, "Constructors", SectionContentCtx::Empty);
+    section.content = SectionContentCtx::Function(FunctionCtx::new(
+      ctx,
+      constructor_nodes.iter().collect(),
+    ));
+
+    return Some(section);
+  }
+
   let items = constructors
     .iter()
     .enumerate()
     .map(|(i, constructor)| {
-      let id = name_to_id("constructor", &i.to_string());
-
-      let params = constructor
-        .params
-        .iter()
-        .map(|param| param.param.clone())
-        .collect::<Vec<_>>();
+      let id = name_to_id(ctx.ctx, "constructor", &i.to_string());
 
-      let params = render_params(ctx, &params);
+      let params = render_constructor_params(ctx, &constructor.params);
 
       DocEntryCtx::new(
         ctx,
@@ -148,6 +180,59 @@ fn render_constructors(
   ))
 }
 
+/// A function node with the signature of a constructor, returning an
+/// instance of the class, used to render overloaded constructors.
+fn constructor_to_doc_node(
+  doc_node: &DocNodeWithContext,
+  constructor: &ClassConstructorDef,
+  name: &str,
+) -> DocNode {
+  let type_ref = |name: &str, type_params| TsTypeDef {
+    repr: name.to_string(),
+    kind: Some(TsTypeDefKind::TypeRef),
+    type_ref: Some(TsTypeRefDef {
+      type_params,
+      type_name: name.to_string(),
+    }),
+    ..Default::default()
+  };
+
+  let type_params = &doc_node.class_def().unwrap().type_params;
+  let instance_type = type_ref(
+    name,
+    (!type_params.is_empty()).then(|| {
+      type_params
+        .iter()
+        .map(|type_param| type_ref(&type_param.name, None))
+        .collect()
+    }),
+  );
+
+  DocNode {
+    name: name.into(),
+    is_default: None,
+    location: constructor.location.clone(),
+    declaration_kind: doc_node.declaration_kind,
+    js_doc: constructor.js_doc.clone(),
+    def: DocNodeDef::Function {
+      function_def: FunctionDef {
+        def_name: None,
+        params: constructor
+          .params
+          .iter()
+          .map(|param| param.param.clone())
+          .collect(),
+        return_type: Some(instance_type),
+        has_body: constructor.has_body,
+        is_async: false,
+        is_generator: false,
+        type_params: Box::new([]),
+        decorators: Box::new([]),
+      },
+    },
+  }
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct IndexSignatureCtx {
   pub id: String,
@@ -315,26 +400,39 @@ fn render_class_accessor(
   let getter_or_setter = getter.or(setter).unwrap();
 
   let name = &getter_or_setter.name;
-  let id = name_to_id("accessor", name);
-  let ts_type = getter
-    .and_then(|getter| getter.function_def.return_type.as_ref())
-    .or_else(|| {
-      setter.and_then(|setter| {
-        setter
-          .function_def
-          .params
-          .first()
-          .and_then(|param| param.ts_type.as_ref())
-      })
-    })
-    .map_or_else(String::new, |ts_type| render_type_def_colon(ctx, ts_type));
+  let id = name_to_id(ctx.ctx, "accessor", name);
+  let getter_type =
+    getter.and_then(|getter| getter.function_def.return_type.as_ref());
+  let setter_type = setter.and_then(|setter| {
+    setter
+      .function_def
+      .params
+      .first()
+      .and_then(|param| param.ts_type.as_ref())
+  });
+  let mut ts_type = getter_type
+    .or(setter_type)
+    .map_or_else(String::new, |ts_type| {
+      render_entry_type_def_colon(ctx, ts_type)
+    });
+
+  // a setter can accept a wider type than its getter returns
+  if let Some((getter_type, setter_type)) = getter_type.zip(setter_type) {
+    if getter_type.to_string() != setter_type.to_string() {
+      ts_type.push_str(&format!(
+        r#"<span class="writableType">writable as {}</span>"#,
+        render_type_def(ctx, setter_type)
+      ));
+    }
+  }
+
   let js_doc = getter_or_setter.js_doc.doc.as_deref();
 
   let mut tags = Tag::from_js_doc(&getter_or_setter.js_doc);
   if let Some(tag) = Tag::from_accessibility(getter_or_setter.accessibility) {
     tags.insert(tag);
   }
-  if getter_or_setter.is_abstract {
+  if getter.into_iter().chain(setter).any(|def| def.is_abstract) {
     tags.insert(Tag::Abstract);
   }
   if getter.is_some() && setter.is_none() {
@@ -369,7 +467,7 @@ fn render_class_method(
     return None;
   }
 
-  let id = name_to_id("method", &format!("{}_{i}", method.name));
+  let id = name_to_id(ctx.ctx, "method", &format!("{}_{i}", method.name));
 
   let mut tags = Tag::from_js_doc(&method.js_doc);
   if let Some(tag) = Tag::from_accessibility(method.accessibility) {
@@ -379,7 +477,7 @@ fn render_class_method(
     tags.insert(Tag::Abstract);
   }
   if method.optional {
-    tags.insert(Tag::Abstract);
+    tags.insert(Tag::Optional);
   }
 
   Some(DocEntryCtx::new(
@@ -391,7 +489,7 @@ fn render_class_method(
       &method.name,
       method.is_static,
     )),
-    &super::function::render_function_summary(&method.function_def, ctx),
+    &super::function::render_function_summary(&method.function_def, ctx, None),
     tags,
     method.js_doc.doc.as_deref(),
     &method.location,
@@ -403,7 +501,7 @@ fn render_class_property(
   class_name: &str,
   property: &ClassPropertyDef,
 ) -> DocEntryCtx {
-  let id = name_to_id("property", &property.name);
+  let id = name_to_id(ctx.ctx, "property", &property.name);
 
   let mut tags = Tag::from_js_doc(&property.js_doc);
   if let Some(tag) = Tag::from_accessibility(property.accessibility) {
@@ -416,13 +514,13 @@ fn render_class_property(
     tags.insert(Tag::Readonly);
   }
   if property.optional {
-    tags.insert(Tag::Abstract);
+    tags.insert(Tag::Optional);
   }
 
   let ts_type = property
     .ts_type
     .as_ref()
-    .map(|ts_type| render_type_def_colon(ctx, ts_type))
+    .map(|ts_type| render_entry_type_def_colon(ctx, ts_type))
     .unwrap_or_default();
 
   DocEntryCtx::new(
@@ -446,39 +544,38 @@ fn render_class_properties(
   class_name: &str,
   properties: Vec<PropertyOrMethod>,
 ) -> Vec<DocEntryCtx> {
-  let mut properties = properties.into_iter().peekable();
+  let mut properties = properties.into_iter().collect::<VecDeque<_>>();
   let mut out = vec![];
 
-  while let Some(property) = properties.next() {
+  while let Some(property) = properties.pop_front() {
     let content = match property {
       PropertyOrMethod::Property(property) => {
         render_class_property(ctx, class_name, &property)
       }
       PropertyOrMethod::Method(method) => {
+        // the other accessor of the pair, which is rendered as one property
+        let other = properties
+          .iter()
+          .position(|other| {
+            other.method().is_some_and(|other| {
+              other.name == method.name
+                && other.is_static == method.is_static
+                && other.kind != method.kind
+            })
+          })
+          .and_then(|i| properties.remove(i))
+          .and_then(|other| match other {
+            PropertyOrMethod::Method(other) => Some(other),
+            PropertyOrMethod::Property(_) => None,
+          });
+
         let (getter, setter) = if method.kind == MethodKind::Getter {
-          let next_is_setter = properties
-            .peek()
-            .and_then(|property| property.method())
-            .is_some_and(|method| method.kind == MethodKind::Setter);
-
-          if next_is_setter {
-            let res = if let PropertyOrMethod::Method(method) =
-              properties.next().unwrap()
-            {
-              method
-            } else {
-              unreachable!()
-            };
-
-            (Some(&method), Some(res))
-          } else {
-            (Some(&method), None)
-          }
+          (Some(method), other)
         } else {
-          (None, Some(method))
+          (other, Some(method))
         };
 
-        render_class_accessor(ctx, class_name, getter, setter.as_ref())
+        render_class_accessor(ctx, class_name, getter.as_ref(), setter.as_ref())
       }
     };
 
@@ -15,6 +15,7 @@ pub(crate) fn render_enum(
     .into_iter()
     .map(|member| {
       let id = name_to_id(
+        render_ctx.ctx,
         "enum",
         &format!("{}_{}", doc_node.get_name(), &member.name),
       );
@@ -1,18 +1,35 @@
 use super::SymbolContentCtx;
 use crate::function::FunctionDef;
+use crate::html::jsdoc::ExampleCtx;
+use crate::html::parameters::expand_tuple_rest;
+use crate::html::parameters::param_name;
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
+use crate::html::types::enum_member_href;
+use crate::html::types::render_entry_type_def;
 use crate::html::types::render_type_def;
 use crate::html::types::render_type_def_colon;
+use crate::html::types::sort_union;
 use crate::html::types::type_params_summary;
 use crate::html::util::*;
 use crate::html::DocNodeWithContext;
+use crate::html::ExamplesPosition;
+use crate::js_doc::JsDoc;
 use crate::js_doc::JsDocTag;
+use crate::node::DocNodeDef;
+use crate::params::AssignTypeDef;
 use crate::params::ParamPatternDef;
+use crate::ts_type::ThisOrIdent;
+use crate::ts_type::TsTypeDef;
+use crate::ts_type::TsTypeDefKind;
 use indexmap::IndexSet;
 use serde::Serialize;
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::Deref;
+use std::rc::Rc;
 
 #[derive(Debug, Serialize, Clone)]
 struct OverloadRenderCtx {
@@ -22,6 +39,19 @@ struct OverloadRenderCtx {
   summary: String,
   deprecated: Option<String>,
   content: SymbolContentCtx,
+  /// The rendered return type heading the overloads returning it, set on the
+  /// first overload of each group.
+  return_type_group: Option<String>,
+  strikethrough: bool,
+}
+
+/// A parameter rendered in a compact list of parameters, with its signature
+/// and description as the tooltip.
+#[derive(Debug, Serialize, Clone)]
+pub struct CompactParamCtx {
+  pub id: String,
+  pub name: String,
+  pub tooltip: String,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -38,22 +68,93 @@ impl FunctionCtx {
   ) -> Self {
     let mut functions_content = Vec::with_capacity(doc_nodes.len());
 
-    let overloads_count = doc_nodes
+    let mut overloads = doc_nodes
       .iter()
+      .copied()
       .enumerate()
       .filter(|(i, doc_node)| {
         let function_def = doc_node.function_def().unwrap();
 
         !(function_def.has_body && *i != 0)
       })
-      .count();
+      .collect::<Vec<_>>();
 
-    for (i, doc_node) in doc_nodes.into_iter().enumerate() {
-      let function_def = doc_node.function_def().unwrap();
+    if ctx.ctx.sort_overloads {
+      overloads.sort_by_key(|(_, doc_node)| {
+        overload_specificity(doc_node.function_def().unwrap())
+      });
+    }
 
-      if function_def.has_body && i != 0 {
-        continue;
-      }
+    let merged_overloads = if ctx.ctx.merge_overloads {
+      merge_overloads(&overloads)
+    } else {
+      vec![]
+    };
+
+    let mut overloads = overloads
+      .into_iter()
+      .filter_map(|(i, doc_node)| {
+        match merged_overloads
+          .iter()
+          .find(|merged| merged.indices.contains(&i))
+        {
+          Some(merged) if merged.indices[0] == i => Some((i, &merged.doc_node)),
+          Some(_) => None,
+          None => Some((i, doc_node)),
+        }
+      })
+      .collect::<Vec<_>>();
+
+    if ctx.ctx.deprecated_overloads_last {
+      overloads.sort_by_key(|(_, doc_node)| {
+        doc_node
+          .js_doc
+          .tags
+          .iter()
+          .any(|tag| matches!(tag, JsDocTag::Deprecated { .. }))
+      });
+    }
+
+    let overloads_count = overloads.len();
+
+    let return_type_groups = ctx
+      .ctx
+      .group_overloads_by_return_type
+      .then(|| {
+        overloads
+          .iter()
+          .map(|(_, doc_node)| return_type_key(doc_node))
+          .collect::<IndexSet<_>>()
+      })
+      .filter(|groups| groups.len() > 1);
+
+    if let Some(groups) = &return_type_groups {
+      overloads.sort_by_key(|(_, doc_node)| {
+        groups.get_index_of(&return_type_key(doc_node))
+      });
+    }
+
+    // an explicit order set by the author takes precedence, even over the
+    // grouping by return type, whose headings are then repeated as needed
+    if overloads
+      .iter()
+      .any(|(_, doc_node)| overload_order(doc_node).is_some())
+    {
+      overloads.sort_by_key(|(_, doc_node)| {
+        let order = overload_order(doc_node);
+        (order.is_none(), order)
+      });
+    }
+
+    let mut previous_return_type = None;
+
+    let implementation = doc_nodes
+      .iter()
+      .copied()
+      .find(|doc_node| doc_node.function_def().unwrap().has_body);
+
+    for (position, (i, doc_node)) in overloads.into_iter().enumerate() {
+      let function_def = doc_node.function_def().unwrap();
 
       let deprecated = doc_node.js_doc.tags.iter().find_map(|tag| {
         if let JsDocTag::Deprecated { doc } = tag {
@@ -68,13 +169,52 @@ impl FunctionCtx {
         }
       });
 
-      let overload_id =
-        name_to_id("function", &format!("{}_{i}", doc_node.get_name()));
+      let overload_id = name_to_id(
+        ctx.ctx,
+        "function",
+        &format!("{}_{i}", doc_node.get_name()),
+      );
 
       if overloads_count > 1 {
-        ctx
-          .toc
-          .add_entry(0, &format!("Overload {}", i + 1), &overload_id);
+        ctx.toc.add_entry(
+          0,
+          &format!("Overload {}", position + 1),
+          &overload_id,
+        );
+      }
+
+      let return_type_group = if return_type_groups.is_some() {
+        let return_type = Some(return_type_key(doc_node));
+
+        if previous_return_type != return_type {
+          previous_return_type = return_type;
+
+          Some(
+            function_def
+              .return_type
+              .as_ref()
+              .map(|return_type| render_type_def(ctx, return_type))
+              .unwrap_or_else(|| "<span>void</span>".to_string()),
+          )
+        } else {
+          None
+        }
+      } else {
+        None
+      };
+
+      let mut summary =
+        render_function_summary(function_def, ctx, Some(&overload_id));
+
+      if let Some(merged) = merged_overloads
+        .iter()
+        .find(|merged| merged.indices[0] == i)
+      {
+        summary.push_str(&format!(
+          r#"<span class="mergedOverloads">merges {} overloads differing in the type of {}</span>"#,
+          merged.indices.len(),
+          merged.param,
+        ));
       }
 
       functions_content.push(OverloadRenderCtx {
@@ -83,9 +223,17 @@ impl FunctionCtx {
           id: overload_id.clone(),
         },
         name: doc_node.get_name().to_string(),
-        summary: render_function_summary(function_def, ctx),
+        summary,
+        strikethrough: deprecated.is_some()
+          && ctx.ctx.strike_deprecated_signatures,
         deprecated,
-        content: render_single_function(ctx, doc_node, &overload_id),
+        content: render_single_function(
+          ctx,
+          doc_node,
+          implementation,
+          &overload_id,
+        ),
+        return_type_group,
       });
     }
 
@@ -95,39 +243,237 @@ impl FunctionCtx {
   }
 }
 
+/// An overload standing in for several overloads which only differ by the
+/// type of one parameter.
+struct MergedOverload {
+  doc_node: DocNodeWithContext,
+  /// The indices of the merged overloads, the first one being the index of
+  /// the overload this replaces.
+  indices: Vec<usize>,
+  /// The rendered name of the parameter whose types got merged.
+  param: String,
+}
+
+/// Group the overloads which only differ by the type of the same parameter,
+/// and merge each group of more than one overload.
+fn merge_overloads(
+  overloads: &[(usize, &DocNodeWithContext)],
+) -> Vec<MergedOverload> {
+  let mut groups: Vec<(Vec<usize>, Option<usize>)> = vec![];
+
+  'overloads: for position in 0..overloads.len() {
+    for (members, param) in groups.iter_mut() {
+      let Some(index) =
+        differing_param_type(overloads[members[0]].1, overloads[position].1)
+      else {
+        continue;
+      };
+
+      if param.map_or(true, |param| param == index) {
+        *param = Some(index);
+        members.push(position);
+        continue 'overloads;
+      }
+    }
+
+    groups.push((vec![position], None));
+  }
+
+  groups
+    .into_iter()
+    .filter_map(|(members, param)| {
+      let param = param?;
+      let first = overloads[members[0]].1;
+      let param_types = members
+        .iter()
+        .filter_map(|position| {
+          overloads[*position].1.function_def().unwrap().params[param]
+            .ts_type
+            .clone()
+        })
+        .collect::<Vec<_>>();
+
+      let mut inner = (*first.inner).clone();
+      let DocNodeDef::Function { function_def } = &mut inner.def else {
+        return None;
+      };
+      function_def.params[param].ts_type = Some(union_of(param_types));
+      let (param, _) = param_name(&function_def.params[param], param);
+
+      Some(MergedOverload {
+        doc_node: DocNodeWithContext {
+          inner: Rc::new(inner),
+          ..first.clone()
+        },
+        indices: members
+          .iter()
+          .map(|position| overloads[*position].0)
+          .collect(),
+        param,
+      })
+    })
+    .collect()
+}
+
+/// The index of the only parameter whose type differs between the two
+/// overloads, if they are identical otherwise, including their docs.
+fn differing_param_type(
+  a: &DocNodeWithContext,
+  b: &DocNodeWithContext,
+) -> Option<usize> {
+  let a_def = a.function_def().unwrap();
+  let b_def = b.function_def().unwrap();
+
+  if a.js_doc != b.js_doc || a_def.params.len() != b_def.params.len() {
+    return None;
+  }
+
+  let mut differing = a_def
+    .params
+    .iter()
+    .zip(b_def.params.iter())
+    .enumerate()
+    .filter(|(_, (a_param, b_param))| a_param != b_param);
+
+  let (index, (a_param, b_param)) = differing.next()?;
+
+  if differing.next().is_some()
+    || a_param.pattern != b_param.pattern
+    || a_param.ts_type.is_none()
+    || b_param.ts_type.is_none()
+  {
+    return None;
+  }
+
+  let without_params = |function_def: &FunctionDef| {
+    serde_json::to_value(FunctionDef {
+      params: vec![],
+      ..function_def.clone()
+    })
+    .ok()
+  };
+
+  (without_params(a_def) == without_params(b_def)).then_some(index)
+}
+
+/// The union of the passed types, flattening unions and skipping duplicates.
+fn union_of(ts_types: Vec<TsTypeDef>) -> TsTypeDef {
+  let mut seen = HashSet::new();
+  let mut union = vec![];
+
+  for ts_type in ts_types {
+    let members = match ts_type.kind {
+      Some(TsTypeDefKind::Union) => ts_type.union.unwrap_or_default(),
+      Some(TsTypeDefKind::FnOrConstructor | TsTypeDefKind::Conditional) => {
+        vec![TsTypeDef {
+          repr: String::new(),
+          kind: Some(TsTypeDefKind::Parenthesized),
+          parenthesized: Some(Box::new(ts_type)),
+          ..Default::default()
+        }]
+      }
+      _ => vec![ts_type],
+    };
+
+    for member in members {
+      if seen.insert(member.to_string()) {
+        union.push(member);
+      }
+    }
+  }
+
+  TsTypeDef {
+    repr: String::new(),
+    kind: Some(TsTypeDefKind::Union),
+    union: Some(union),
+    ..Default::default()
+  }
+}
+
+/// The position of an overload set with an `@overloadOrder N` tag.
+fn overload_order(doc_node: &DocNodeWithContext) -> Option<i64> {
+  doc_node.js_doc.tags.iter().find_map(|tag| {
+    let JsDocTag::Unsupported { value } = tag else {
+      return None;
+    };
+
+    value
+      .strip_prefix("@overloadOrder")?
+      .strip_prefix(char::is_whitespace)?
+      .split_whitespace()
+      .next()?
+      .parse()
+      .ok()
+  })
+}
+
+/// The key overloads are grouped by when grouping them by return type.
+fn return_type_key(doc_node: &DocNodeWithContext) -> String {
+  doc_node
+    .function_def()
+    .unwrap()
+    .return_type
+    .as_ref()
+    .map(|return_type| return_type.to_string())
+    .unwrap_or_default()
+}
+
+/// The sort key of an overload, where more specific overloads, ie. ones with
+/// fewer type parameters and more literal typed parameters, come first.
+fn overload_specificity(function_def: &FunctionDef) -> (usize, Reverse<usize>) {
+  let literal_params = function_def
+    .params
+    .iter()
+    .filter(|param| {
+      param
+        .ts_type
+        .as_ref()
+        .is_some_and(|ts_type| ts_type.kind == Some(TsTypeDefKind::Literal))
+    })
+    .count();
+
+  (function_def.type_params.len(), Reverse(literal_params))
+}
+
+/// Render the signature of a function. The `overload_id` is used to link
+/// the return type like in the return type section if enabled.
 pub(crate) fn render_function_summary(
   function_def: &FunctionDef,
   render_ctx: &RenderContext,
+  overload_id: Option<&str>,
 ) -> String {
   let return_type = function_def
     .return_type
     .as_ref()
-    .map(|ts_type| render_type_def_colon(render_ctx, ts_type))
+    .map(|ts_type| {
+      let linked = overload_id
+        .filter(|_| render_ctx.ctx.link_summary_return_type)
+        .and_then(|overload_id| {
+          render_type_predicate(render_ctx, function_def, ts_type, overload_id)
+        });
+
+      match linked {
+        Some(linked) => format!("<span>: {linked}</span>"),
+        None => render_type_def_colon(render_ctx, ts_type),
+      }
+    })
     .unwrap_or_default();
 
   format!(
     "{}({}){return_type}",
     type_params_summary(render_ctx, &function_def.type_params),
-    render_params(render_ctx, &function_def.params)
+    render_params(
+      render_ctx,
+      &expand_tuple_rest(render_ctx, &function_def.params)
+    )
   )
 }
 
-fn render_single_function(
-  ctx: &RenderContext,
-  doc_node: &DocNodeWithContext,
-  overload_id: &str,
-) -> SymbolContentCtx {
-  let function_def = doc_node.function_def().unwrap();
-
-  let current_type_params = function_def
-    .type_params
-    .iter()
-    .map(|def| def.name.as_str())
-    .collect::<HashSet<&str>>();
-  let ctx = &ctx.with_current_type_params(current_type_params);
+type ParamDocs<'a> =
+  HashMap<&'a str, (&'a Option<Box<str>>, bool, &'a Option<Box<str>>)>;
 
-  let param_docs = doc_node
-    .js_doc
+fn collect_param_docs(js_doc: &JsDoc) -> ParamDocs {
+  js_doc
     .tags
     .iter()
     .filter_map(|tag| {
@@ -144,64 +490,221 @@ fn render_single_function(
         None
       }
     })
-    .collect::<std::collections::HashMap<
-      &str,
-      (&Option<Box<str>>, bool, &Option<Box<str>>),
-    >>();
+    .collect()
+}
+
+type NarrowedParams<'a> = HashMap<&'a str, (&'a str, &'a Option<Box<str>>)>;
+
+fn collect_narrowed_params(js_doc: &JsDoc) -> NarrowedParams {
+  js_doc
+    .tags
+    .iter()
+    .filter_map(|tag| {
+      if let JsDocTag::Narrows {
+        name,
+        type_ref,
+        doc,
+      } = tag
+      {
+        Some((name.deref(), (type_ref.deref(), doc)))
+      } else {
+        None
+      }
+    })
+    .collect()
+}
+
+/// Render the note for a parameter documented with `@narrows name to T`,
+/// linking `T` when it refers to a documented symbol.
+fn render_narrowed_type(
+  ctx: &RenderContext,
+  type_ref: &str,
+  doc: Option<&str>,
+) -> String {
+  let rendered = type_ref
+    .chars()
+    .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.'))
+    .then(|| ctx.lookup_symbol_href(type_ref))
+    .flatten()
+    .map(|href| {
+      format!(
+        r#"<a href="{}" class="link">{}</a>"#,
+        html_escape::encode_double_quoted_attribute(&href),
+        html_escape::encode_text(type_ref),
+      )
+    })
+    .unwrap_or_else(|| link_type_params(ctx, type_ref));
+
+  let title = doc
+    .map(|doc| {
+      format!(
+        r#" title="{}""#,
+        html_escape::encode_double_quoted_attribute(doc)
+      )
+    })
+    .unwrap_or_default();
+
+  format!(r#"<span class="narrowedType"{title}>narrowed to {rendered}</span>"#)
+}
+
+fn render_single_function(
+  ctx: &RenderContext,
+  doc_node: &DocNodeWithContext,
+  implementation: Option<&DocNodeWithContext>,
+  overload_id: &str,
+) -> SymbolContentCtx {
+  let function_def = doc_node.function_def().unwrap();
+
+  let current_type_params = function_def
+    .type_params
+    .iter()
+    .map(|def| def.name.as_str())
+    .collect::<HashSet<&str>>();
+  let ctx = &ctx.with_current_type_params(current_type_params);
 
-  let params = function_def
+  let param_docs = collect_param_docs(&doc_node.js_doc);
+  // overloads often leave the parameter docs to the implementation signature
+  let implementation_param_docs = implementation
+    .map(|implementation| collect_param_docs(&implementation.js_doc))
+    .unwrap_or_default();
+  let mut narrowed_params = implementation
+    .map(|implementation| collect_narrowed_params(&implementation.js_doc))
+    .unwrap_or_default();
+  narrowed_params.extend(collect_narrowed_params(&doc_node.js_doc));
+
+  let (params, compact_params) = function_def
     .params
     .iter()
     .enumerate()
-    .map(|(i, param)| {
+    .filter_map(|(i, param)| {
       let (name, str_name) = crate::html::parameters::param_name(param, i);
-      let id = name_to_id(overload_id, &format!("parameters_{str_name}"));
+      let id =
+        name_to_id(ctx.ctx, overload_id, &format!("parameters_{str_name}"));
+
+      // rest parameters are documented by the name of their binding
+      let doc_name = match &param.pattern {
+        ParamPatternDef::Rest { arg } => param_name(arg, i).0,
+        _ => name.clone(),
+      };
+
+      let param_doc = param_docs
+        .get(doc_name.as_str())
+        .and_then(|(doc, _, _)| doc.as_deref())
+        .or_else(|| {
+          implementation_param_docs
+            .get(doc_name.as_str())
+            .and_then(|(doc, _, _)| doc.as_deref())
+        });
+      let (internal, param_doc) = strip_internal_marker(param_doc);
+
+      if internal && ctx.ctx.hide_internal_params {
+        return None;
+      }
 
       let (mut default, optional) = if let Some((_doc, optional, default)) =
-        param_docs.get(name.as_str())
+        param_docs.get(doc_name.as_str())
       {
         ((**default).to_owned(), *optional)
       } else {
         (None, false)
       };
 
-      let ts_type =
-        if let ParamPatternDef::Assign { left, right } = &param.pattern {
-          default = default.or(Some(right.deref().into()));
-          left.ts_type.as_ref()
-        } else {
-          param.ts_type.as_ref()
-        };
+      // marking a rest parameter as optional is invalid, which is reported
+      // as a diagnostic instead
+      let optional =
+        optional && !matches!(param.pattern, ParamPatternDef::Rest { .. });
 
-      let mut ts_type = ts_type
-        .map(|ts_type| render_type_def_colon(ctx, ts_type))
-        .unwrap_or_default();
+      let mut default_type = None;
 
-      if let Some(default) = &default {
-        if default.deref() != "[UNSUPPORTED]" {
-          ts_type = format!(r#"{ts_type}<span><span class="font-normal"> = </span>{default}</span>"#);
+      let (ts_type, type_comment) = if let ParamPatternDef::Assign {
+        left,
+        right,
+        right_type,
+      } = &param.pattern
+      {
+        if default.is_none() {
+          default = Some(right.deref().into());
+          default_type = right_type.as_ref();
         }
-      }
+        (left.ts_type.as_ref(), left.type_comment.as_deref())
+      } else {
+        (param.ts_type.as_ref(), param.type_comment.as_deref())
+      };
 
-      let tags = if matches!(
+      let optional = matches!(
         param.pattern,
         ParamPatternDef::Array { optional, .. }
           | ParamPatternDef::Identifier { optional, .. }
           | ParamPatternDef::Object { optional, .. }
         if optional
       ) || default.is_some()
-        || optional
+        || optional;
+
+      let mut ts_type = ts_type
+        .map(|ts_type| {
+          let ts_type = if optional && ctx.ctx.optional_param_undefined {
+            with_undefined(ts_type)
+          } else {
+            Cow::Borrowed(ts_type)
+          };
+
+          format!("<span>: {}</span>", render_param_type(ctx, &ts_type))
+        })
+        .unwrap_or_default();
+
+      if let Some(type_comment) =
+        type_comment.filter(|_| ctx.ctx.param_type_comments)
       {
+        ts_type.push_str(&format!(
+          r#"<span class="typeComment">{}</span>"#,
+          html_escape::encode_text(type_comment)
+        ));
+      }
+
+      if let Some((type_ref, doc)) = narrowed_params.get(doc_name.as_str()) {
+        ts_type.push_str(&render_narrowed_type(ctx, type_ref, doc.as_deref()));
+      }
+
+      let mut tooltip = param.to_string();
+
+      if let Some(default) = &default {
+        if default.deref() != "[UNSUPPORTED]" {
+          tooltip.push_str(&format!(" = {default}"));
+          if let Some(default_type) = default_type {
+            tooltip.push_str(&format!(
+              " {} {}",
+              default_type.operator, default_type.ts_type
+            ));
+          }
+          ts_type = format!(
+            r#"{ts_type}<span><span class="font-normal"> = </span>{}</span>"#,
+            render_default_value(ctx, default, default_type)
+          );
+        }
+      }
+
+      let mut tags = if optional {
         IndexSet::from([Tag::Optional])
       } else {
         IndexSet::new()
       };
 
-      let param_doc = param_docs
-        .get(name.as_str())
-        .and_then(|(doc, _, _)| doc.as_deref());
+      if internal {
+        tags.insert(Tag::Other("internal".into()));
+      }
 
-      DocEntryCtx::new(
+      if let Some(param_doc) = param_doc {
+        tooltip.push('\n');
+        tooltip.push_str(&crate::html::jsdoc::strip(ctx, param_doc));
+      }
+
+      let compact_param = CompactParamCtx {
+        id: id.clone(),
+        name: name.clone(),
+        tooltip,
+      };
+
+      let mut doc_entry = DocEntryCtx::new(
         ctx,
         &id,
         Some(name),
@@ -210,18 +713,47 @@ fn render_single_function(
         tags,
         param_doc,
         &doc_node.location,
-      )
+      );
+      doc_entry.dimmed = internal;
+
+      Some((doc_entry, compact_param))
     })
-    .collect::<Vec<DocEntryCtx>>();
+    .collect::<(Vec<DocEntryCtx>, Vec<CompactParamCtx>)>();
 
   let mut sections = vec![];
 
-  let docs =
+  let mut docs =
     crate::html::jsdoc::jsdoc_body_to_html(ctx, &doc_node.js_doc, false);
-  let examples = crate::html::jsdoc::jsdoc_examples(ctx, &doc_node.js_doc);
+  let mut examples = if ctx.ctx.inline_first_example {
+    let (first, examples) = crate::html::jsdoc::jsdoc_examples_split_first(
+      ctx,
+      &doc_node.js_doc,
+      Some(&doc_node.get_qualified_name()),
+    );
 
-  if let Some(examples) = examples {
-    sections.push(examples);
+    if let Some(first) = first {
+      let first = ctx.ctx.render(ExampleCtx::TEMPLATE, &first);
+      docs = Some(format!("{}{first}", docs.unwrap_or_default()));
+    }
+
+    examples
+  } else {
+    crate::html::jsdoc::jsdoc_examples(
+      ctx,
+      &doc_node.js_doc,
+      Some(&doc_node.get_qualified_name()),
+    )
+  };
+
+  match ctx.ctx.examples_position {
+    ExamplesPosition::BeforeDocs => {
+      if let Some(examples) = examples.take() {
+        let examples = ctx.ctx.render(SectionCtx::TEMPLATE, &examples);
+        docs = Some(format!("{examples}{}", docs.unwrap_or_default()));
+      }
+    }
+    ExamplesPosition::AfterDocs => sections.extend(examples.take()),
+    ExamplesPosition::End => {}
   }
 
   if let Some(type_params) = crate::html::types::render_type_params(
@@ -237,18 +769,36 @@ fn render_single_function(
     sections.push(SectionCtx::new(
       ctx,
       "Parameters",
-      SectionContentCtx::DocEntry(params),
+      if ctx.ctx.compact_params {
+        SectionContentCtx::CompactParams(compact_params)
+      } else {
+        SectionContentCtx::DocEntry(params)
+      },
     ));
   }
 
-  sections.push(SectionCtx::new(
-    ctx,
-    "Return Type",
-    SectionContentCtx::DocEntry(
-      render_function_return_type(ctx, function_def, doc_node, overload_id)
-        .map_or_else(Default::default, |doc_entry| vec![doc_entry]),
-    ),
-  ));
+  let return_type =
+    render_function_return_type(ctx, function_def, doc_node, overload_id);
+
+  if return_type.is_some() || !ctx.ctx.hide_void_return_type {
+    sections.push(SectionCtx::new(
+      ctx,
+      "Return Type",
+      SectionContentCtx::DocEntry(
+        return_type.map_or_else(Default::default, |doc_entry| vec![doc_entry]),
+      ),
+    ));
+  }
+
+  if let Some(yields) =
+    render_function_yields(ctx, function_def, doc_node, overload_id)
+  {
+    sections.push(SectionCtx::new(
+      ctx,
+      "Yields",
+      SectionContentCtx::DocEntry(vec![yields]),
+    ));
+  }
 
   let throws = doc_node
     .js_doc
@@ -298,6 +848,21 @@ fn render_single_function(
     ));
   }
 
+  sections.extend(crate::html::jsdoc::jsdoc_custom_tags(ctx, &doc_node.js_doc));
+  sections.extend(examples);
+
+  if ctx
+    .ctx
+    .section_toc_threshold
+    .is_some_and(|threshold| sections.len() >= threshold)
+  {
+    docs = Some(format!(
+      "{}{}",
+      docs.unwrap_or_default(),
+      render_section_toc(&sections)
+    ));
+  }
+
   SymbolContentCtx {
     id: String::new(),
     sections,
@@ -305,6 +870,183 @@ fn render_single_function(
   }
 }
 
+/// A list of links to the passed sections, shown above them.
+fn render_section_toc(sections: &[SectionCtx]) -> String {
+  let mut links = String::new();
+
+  for section in sections {
+    links.push_str(&format!(
+      r##"<a href="#{}">{}</a>"##,
+      html_escape::encode_double_quoted_attribute(&section.header.anchor.id),
+      html_escape::encode_text(&section.header.title),
+    ));
+  }
+
+  format!(r#"<nav class="sectionToc">{links}</nav>"#)
+}
+
+/// Render the type of a parameter, collapsing it behind a "show full type"
+/// toggle if its text is longer than `collapse_param_types_over`.
+fn render_param_type(ctx: &RenderContext, ts_type: &TsTypeDef) -> String {
+  let rendered = render_entry_type_def(ctx, ts_type);
+
+  let Some(threshold) = ctx.ctx.collapse_param_types_over else {
+    return rendered;
+  };
+
+  if ts_type.to_string().chars().count() <= threshold {
+    return rendered;
+  }
+
+  let label = match (&ts_type.kind, &ts_type.type_ref) {
+    (Some(TsTypeDefKind::TypeRef), Some(type_ref)) => {
+      html_escape::encode_text(&type_ref.type_name).into_owned()
+    }
+    (Some(TsTypeDefKind::TypeLiteral), _) => "{ … }".to_string(),
+    _ => "…".to_string(),
+  };
+
+  format!(
+    r#"<details class="collapsedType"><summary>{label}<span>show full type</span></summary>{rendered}</details>"#
+  )
+}
+
+/// Strips the `@internal` marker from the start of a `@param` description,
+/// returning whether the marker was present.
+fn strip_internal_marker(doc: Option<&str>) -> (bool, Option<&str>) {
+  let Some(stripped) = doc.and_then(|doc| {
+    let doc = doc.trim_start();
+    doc
+      .strip_prefix("{@internal}")
+      .or_else(|| doc.strip_prefix("@internal"))
+      .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+  }) else {
+    return (false, doc);
+  };
+
+  let stripped = stripped.trim_start();
+  (true, (!stripped.is_empty()).then_some(stripped))
+}
+
+/// Extends the type of an optional parameter to explicitly include
+/// `undefined`, unless it already does.
+fn with_undefined(ts_type: &TsTypeDef) -> Cow<TsTypeDef> {
+  let is_undefined = |ts_type: &TsTypeDef| {
+    ts_type.keyword.as_deref().is_some_and(|keyword| {
+      matches!(keyword, "undefined" | "any" | "unknown" | "void")
+    })
+  };
+
+  if is_undefined(ts_type) {
+    return Cow::Borrowed(ts_type);
+  }
+
+  let mut union = match ts_type.kind {
+    Some(TsTypeDefKind::Union) => {
+      let union = ts_type.union.as_ref().unwrap();
+      if union.iter().any(is_undefined) {
+        return Cow::Borrowed(ts_type);
+      }
+      union.clone()
+    }
+    Some(TsTypeDefKind::FnOrConstructor | TsTypeDefKind::Conditional) => {
+      vec![TsTypeDef {
+        repr: String::new(),
+        kind: Some(TsTypeDefKind::Parenthesized),
+        parenthesized: Some(Box::new(ts_type.clone())),
+        ..Default::default()
+      }]
+    }
+    _ => vec![ts_type.clone()],
+  };
+  union.push(TsTypeDef::keyword("undefined"));
+
+  Cow::Owned(TsTypeDef {
+    repr: String::new(),
+    kind: Some(TsTypeDefKind::Union),
+    union: Some(union),
+    ..Default::default()
+  })
+}
+
+/// Renders a default value, linking the type of a trailing `satisfies` or
+/// `as` clause if present.
+fn render_default_value(
+  ctx: &RenderContext,
+  default: &str,
+  default_type: Option<&AssignTypeDef>,
+) -> String {
+  if let Some(default_type) = default_type {
+    return format!(
+      r#"{}<span> {} </span>{}"#,
+      html_escape::encode_text(default),
+      default_type.operator,
+      render_type_def(ctx, &default_type.ts_type),
+    );
+  }
+
+  // member expressions are stored in brackets, eg. `[[ns.Color].Red]`
+  let member = default.replace(['[', ']'], "");
+  if default.starts_with('[')
+    && member
+      .chars()
+      .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.'))
+  {
+    if let Some(href) = enum_member_href(ctx, &member) {
+      return format!(
+        r#"<a href="{}" class="link">{}</a>"#,
+        html_escape::encode_double_quoted_attribute(&href),
+        html_escape::encode_text(&member),
+      );
+    }
+  }
+
+  html_escape::encode_text(default).into_owned()
+}
+
+/// Escape a type, linking the identifiers outside of string literals which
+/// refer to a type parameter in scope, eg. `T` in `{} as T`.
+fn link_type_params(ctx: &RenderContext, ts_type: &str) -> String {
+  fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$')
+  }
+
+  let mut out = String::with_capacity(ts_type.len());
+  let mut quote = None;
+  let mut rest = ts_type;
+
+  while let Some(char) = rest.chars().next() {
+    let len = if quote.is_none() && is_ident_char(char) {
+      let len = rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len());
+      let ident = &rest[..len];
+
+      if ctx.contains_type_param(ident) {
+        out.push_str(&format!(
+          r##"<a href="#{}" class="link">{ident}</a>"##,
+          name_to_id(ctx.ctx, "type_param", ident)
+        ));
+      } else {
+        out.push_str(&html_escape::encode_text(ident));
+      }
+
+      len
+    } else {
+      match (quote, char) {
+        (None, '"' | '\'' | '`') => quote = Some(char),
+        (Some(quote_char), _) if quote_char == char => quote = None,
+        _ => {}
+      }
+
+      out.push_str(&html_escape::encode_text(&rest[..char.len_utf8()]));
+      char.len_utf8()
+    };
+
+    rest = &rest[len..];
+  }
+
+  out
+}
+
 fn render_function_return_type(
   render_ctx: &RenderContext,
   def: &FunctionDef,
@@ -313,7 +1055,7 @@ fn render_function_return_type(
 ) -> Option<DocEntryCtx> {
   let return_type = def.return_type.as_ref()?;
 
-  let id = name_to_id(overload_id, "return");
+  let id = name_to_id(render_ctx.ctx, overload_id, "return");
 
   let return_type_doc = doc_node.js_doc.tags.iter().find_map(|tag| {
     if let JsDocTag::Return { doc, .. } = tag {
@@ -323,18 +1065,180 @@ fn render_function_return_type(
     }
   });
 
+  if render_ctx.ctx.hide_void_return_type
+    && return_type_doc.is_none()
+    && return_type.keyword.as_deref() == Some("void")
+  {
+    return None;
+  }
+
+  let sorted_return_type;
+  let return_type = if render_ctx.ctx.sort_union_return_types
+    && return_type.kind == Some(TsTypeDefKind::Union)
+  {
+    sorted_return_type = TsTypeDef {
+      union: return_type.union.as_deref().map(sort_union),
+      ..return_type.clone()
+    };
+    &sorted_return_type
+  } else {
+    return_type
+  };
+
+  // methods returning `this` are part of a fluent API
+  let tags = if return_type.kind == Some(TsTypeDefKind::This) {
+    IndexSet::from([Tag::Other("chainable".into())])
+  } else {
+    IndexSet::new()
+  };
+
+  let mut content =
+    render_type_predicate(render_ctx, def, return_type, overload_id)
+      .unwrap_or_else(|| render_type_def(render_ctx, return_type));
+
+  if render_ctx.ctx.awaited_return_type {
+    if let Some(awaited) = promise_inner_type(return_type) {
+      content.push_str(&format!(
+        r#"<span class="awaitedType">resolves to {}</span>"#,
+        render_type_def(render_ctx, awaited)
+      ));
+    }
+  }
+
+  // long return types wrap at any point instead of overflowing the section
+  let content = format!(r#"<span class="returnType">{content}</span>"#);
+
   Some(DocEntryCtx::new(
     render_ctx,
     &id,
     None,
     None,
-    &render_type_def(render_ctx, return_type),
-    IndexSet::new(),
+    &content,
+    tags,
     return_type_doc,
     &doc_node.location,
   ))
 }
 
+/// Render a type predicate return type, eg. `asserts x is T`, with the
+/// asserted binding linked to its parameter.
+fn render_type_predicate(
+  render_ctx: &RenderContext,
+  def: &FunctionDef,
+  return_type: &TsTypeDef,
+  overload_id: &str,
+) -> Option<String> {
+  let type_predicate = return_type.type_predicate.as_ref()?;
+
+  let ThisOrIdent::Identifier { name } = &type_predicate.param else {
+    return None;
+  };
+
+  def
+    .params
+    .iter()
+    .enumerate()
+    .find(|(i, param)| param_name(param, *i).1 == *name)?;
+
+  let id =
+    name_to_id(render_ctx.ctx, overload_id, &format!("parameters_{name}"));
+
+  let asserts = type_predicate
+    .asserts
+    .then_some("<span>asserts </span>")
+    .unwrap_or_default();
+
+  let r#type = type_predicate
+    .r#type
+    .as_ref()
+    .map(|def| format!(" is {}", render_type_def(render_ctx, def)))
+    .unwrap_or_default();
+
+  Some(format!(
+    r##"{asserts}<a href="#{}" class="link">{}</a>{type}"##,
+    html_escape::encode_double_quoted_attribute(&id),
+    html_escape::encode_text(name),
+  ))
+}
+
+/// The type argument of a `Promise<T>` type.
+fn promise_inner_type(ts_type: &TsTypeDef) -> Option<&TsTypeDef> {
+  let type_ref = ts_type.type_ref.as_ref()?;
+
+  if type_ref.type_name != "Promise" {
+    return None;
+  }
+
+  match type_ref.type_params.as_deref()? {
+    [inner] => Some(inner),
+    _ => None,
+  }
+}
+
+/// Render the `@yields` tag of a generator function, with the yielded type
+/// taken from the return type if the tag has none.
+fn render_function_yields(
+  render_ctx: &RenderContext,
+  def: &FunctionDef,
+  doc_node: &DocNodeWithContext,
+  overload_id: &str,
+) -> Option<DocEntryCtx> {
+  if !def.is_generator {
+    return None;
+  }
+
+  let (type_ref, doc) = doc_node.js_doc.tags.iter().find_map(|tag| {
+    if let JsDocTag::Yields { type_ref, doc } = tag {
+      Some((type_ref, doc))
+    } else {
+      None
+    }
+  })?;
+
+  let content = if let Some(type_ref) = type_ref {
+    format!("<span>{}</span>", html_escape::encode_text(type_ref))
+  } else {
+    def
+      .return_type
+      .as_ref()
+      .and_then(yielded_type)
+      .map(|yielded| render_type_def(render_ctx, yielded))
+      .unwrap_or_default()
+  };
+
+  Some(DocEntryCtx::new(
+    render_ctx,
+    &name_to_id(render_ctx.ctx, overload_id, "yields"),
+    None,
+    None,
+    &content,
+    IndexSet::new(),
+    doc.as_deref(),
+    &doc_node.location,
+  ))
+}
+
+/// The type yielded by a generator, eg. `T` for `Generator<T>`.
+fn yielded_type(ts_type: &TsTypeDef) -> Option<&TsTypeDef> {
+  let type_ref = ts_type.type_ref.as_ref()?;
+
+  if !matches!(
+    type_ref.type_name.as_str(),
+    "Generator"
+      | "AsyncGenerator"
+      | "Iterator"
+      | "AsyncIterator"
+      | "IterableIterator"
+      | "AsyncIterableIterator"
+      | "Iterable"
+      | "AsyncIterable"
+  ) {
+    return None;
+  }
+
+  type_ref.type_params.as_deref()?.first()
+}
+
 fn render_function_throws(
   render_ctx: &RenderContext,
   doc_node: &DocNodeWithContext,
@@ -343,7 +1247,8 @@ fn render_function_throws(
   overload_id: &str,
   throws_id: usize,
 ) -> DocEntryCtx {
-  let id = name_to_id(overload_id, &format!("throws_{throws_id}"));
+  let id =
+    name_to_id(render_ctx.ctx, overload_id, &format!("throws_{throws_id}"));
 
   DocEntryCtx::new(
     render_ctx,
@@ -1,6 +1,7 @@
 use crate::html::parameters::render_params;
 use crate::html::render_context::RenderContext;
 use crate::html::symbols::class::IndexSignatureCtx;
+use crate::html::types::render_entry_type_def_colon;
 use crate::html::types::render_type_def_colon;
 use crate::html::types::type_params_summary;
 use crate::html::util::*;
@@ -67,7 +68,7 @@ pub(crate) fn render_index_signatures(
   let mut items = Vec::with_capacity(index_signatures.len());
 
   for (i, index_signature) in index_signatures.iter().enumerate() {
-    let id = name_to_id("index_signature", &i.to_string());
+    let id = name_to_id(ctx.ctx, "index_signature", &i.to_string());
 
     let ts_type = index_signature
       .ts_type
@@ -107,7 +108,7 @@ pub(crate) fn render_call_signatures(
     .iter()
     .enumerate()
     .map(|(i, call_signature)| {
-      let id = name_to_id("call_signature", &i.to_string());
+      let id = name_to_id(ctx.ctx, "call_signature", &i.to_string());
 
       let ts_type = call_signature
         .ts_type
@@ -153,7 +154,7 @@ pub(crate) fn render_properties(
   let items = properties
     .iter()
     .map(|property| {
-      let id = name_to_id("property", &property.name);
+      let id = name_to_id(ctx.ctx, "property", &property.name);
       let default_value = property
         .js_doc
         .tags
@@ -173,7 +174,7 @@ pub(crate) fn render_properties(
       let ts_type = property
         .ts_type
         .as_ref()
-        .map(|ts_type| render_type_def_colon(ctx, ts_type))
+        .map(|ts_type| render_entry_type_def_colon(ctx, ts_type))
         .unwrap_or_default();
 
       let mut tags = Tag::from_js_doc(&property.js_doc);
@@ -225,7 +226,8 @@ pub(crate) fn render_methods(
     .iter()
     .enumerate()
     .map(|(i, method)| {
-      let id = name_to_id("call_signature", &format!("{}_{i}", method.name));
+      let id =
+        name_to_id(ctx.ctx, "call_signature", &format!("{}_{i}", method.name));
 
       let name = if method.name == "new" {
         "<span>new</span>".to_string()
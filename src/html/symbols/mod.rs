@@ -1,11 +1,13 @@
 use crate::html::types::render_type_def;
 use crate::html::usage::UsagesCtx;
+use crate::html::util::DocEntryCtx;
 use crate::html::util::SectionCtx;
 use crate::html::util::Tag;
 use crate::html::util::{AnchorCtx, SectionContentCtx};
 use crate::html::DocNodeKindWithDrilldown;
 use crate::html::DocNodeWithContext;
 use crate::html::RenderContext;
+use crate::html::UrlResolveKind;
 use crate::js_doc::JsDocTag;
 use crate::node::DocNodeDef;
 use crate::DocNodeKind;
@@ -22,6 +24,239 @@ pub mod namespace;
 pub mod type_alias;
 pub mod variable;
 
+/// Whether the doc node is the drilldown of a method which is itself
+/// optional, eg. `foo?(): void` in an interface.
+fn is_optional_method(doc_node: &DocNodeWithContext) -> bool {
+  let (Some(parent), Some(name), DocNodeKindWithDrilldown::Method(_)) = (
+    &doc_node.parent,
+    &doc_node.drilldown_name,
+    doc_node.kind_with_drilldown,
+  ) else {
+    return false;
+  };
+
+  let type_literal = match &parent.def {
+    DocNodeDef::Class { class_def } => {
+      return class_def
+        .methods
+        .iter()
+        .any(|method| method.name == *name && method.optional);
+    }
+    DocNodeDef::Interface { interface_def } => {
+      return interface_def
+        .methods
+        .iter()
+        .any(|method| *method.name == **name && method.optional);
+    }
+    DocNodeDef::TypeAlias { type_alias_def } => {
+      type_alias_def.ts_type.type_literal.as_ref()
+    }
+    DocNodeDef::Variable { variable_def } => variable_def
+      .ts_type
+      .as_ref()
+      .and_then(|ts_type| ts_type.type_literal.as_ref()),
+    _ => None,
+  };
+
+  type_literal.is_some_and(|type_literal| {
+    type_literal
+      .methods
+      .iter()
+      .any(|method| *method.name == **name && method.optional)
+  })
+}
+
+/// Whether the symbol was added on or after the `new_since` threshold.
+fn is_new_symbol(ctx: &RenderContext, doc_node: &DocNodeWithContext) -> bool {
+  let Some(new_since) = &ctx.ctx.new_since else {
+    return false;
+  };
+
+  let added = ctx
+    .ctx
+    .symbol_added
+    .as_ref()
+    .and_then(|symbol_added| symbol_added.get(&doc_node.get_qualified_name()))
+    .map(|added| added.as_str())
+    .or_else(|| {
+      doc_node.js_doc.tags.iter().find_map(|tag| {
+        if let JsDocTag::Since { doc } = tag {
+          Some(doc.trim())
+        } else {
+          None
+        }
+      })
+    });
+
+  added.is_some_and(|added| {
+    super::util::compare_versions(added, new_since).is_ge()
+  })
+}
+
+/// The path of the file the symbol is defined in, relative to
+/// `source_path_root` if set, or to the root of the documented files.
+fn source_path(ctx: &RenderContext, doc_node: &DocNodeWithContext) -> String {
+  let Some(root) = &ctx.ctx.source_path_root else {
+    let path = doc_node.origin.path.trim_start_matches("./");
+
+    // the path of a single documented file is the root itself
+    return if path == "." {
+      doc_node
+        .origin
+        .specifier
+        .path()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+    } else {
+      path.to_string()
+    };
+  };
+
+  let filename = &*doc_node.location.filename;
+
+  filename
+    .strip_prefix(root.as_str())
+    .map_or(filename, |path| path.trim_start_matches('/'))
+    .to_string()
+}
+
+/// Whether the namespace only holds variables, eg. the namespace of expando
+/// properties assigned to a function, so that they are static properties of
+/// the function of the same name.
+fn is_properties_namespace(doc_node: &DocNodeWithContext) -> bool {
+  doc_node.namespace_def().is_some_and(|namespace_def| {
+    !namespace_def.elements.is_empty()
+      && namespace_def
+        .elements
+        .iter()
+        .all(|element| element.kind() == DocNodeKind::Variable)
+  })
+}
+
+/// Render the variables of the namespaces merged with a function as the
+/// static properties of the function.
+fn render_function_properties(
+  ctx: &RenderContext,
+  namespace_nodes: &[DocNodeWithContext],
+) -> Option<SectionCtx> {
+  // expando properties are also part of an explicit namespace of the function
+  let mut seen = HashSet::new();
+
+  let items = namespace_nodes
+    .iter()
+    .flat_map(|doc_node| {
+      let ns_qualifiers: std::rc::Rc<[String]> =
+        doc_node.sub_qualifier().into();
+
+      doc_node
+        .namespace_def()
+        .unwrap()
+        .elements
+        .iter()
+        .map(move |element| {
+          doc_node
+            .create_namespace_child(element.clone(), ns_qualifiers.clone())
+        })
+    })
+    .filter(|property| !property.is_internal())
+    .filter(|property| seen.insert(property.get_qualified_name()))
+    .map(|property| {
+      let qualified_name = property.get_qualified_name();
+
+      let ts_type = property
+        .variable_def()
+        .unwrap()
+        .ts_type
+        .as_ref()
+        .map(|ts_type| super::types::render_type_def_colon(ctx, ts_type))
+        .unwrap_or_default();
+
+      DocEntryCtx::new(
+        ctx,
+        &super::util::name_to_id(ctx.ctx, "property", &qualified_name),
+        Some(html_escape::encode_text(property.get_name()).into_owned()),
+        ctx.lookup_symbol_href(&qualified_name),
+        &ts_type,
+        Tag::from_js_doc(&property.js_doc),
+        property.js_doc.doc.as_deref(),
+        &property.location,
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if items.is_empty() {
+    return None;
+  }
+
+  Some(SectionCtx::new(
+    ctx,
+    "Properties",
+    SectionContentCtx::DocEntry(items),
+  ))
+}
+
+fn category_of(doc_node: &DocNodeWithContext) -> Option<&str> {
+  doc_node.js_doc.tags.iter().find_map(|tag| {
+    if let JsDocTag::Category { doc } = tag {
+      Some(doc.trim())
+    } else {
+      None
+    }
+  })
+}
+
+/// Render links to the other functions which share the category of the
+/// passed function.
+fn render_related_functions(
+  ctx: &RenderContext,
+  doc_node: &DocNodeWithContext,
+) -> Option<SectionCtx> {
+  let category = category_of(doc_node)?;
+  let mut seen = HashSet::new();
+
+  let items = ctx
+    .ctx
+    .doc_nodes
+    .values()
+    .flatten()
+    .filter(|related| {
+      related.kind() == DocNodeKind::Function
+        && !related.is_internal()
+        && category_of(related) == Some(category)
+        && !(related.origin == doc_node.origin
+          && related.get_name() == doc_node.get_name())
+    })
+    .filter(|related| seen.insert((&related.origin, related.get_name())))
+    .map(|related| {
+      let href = ctx.ctx.resolve_path(
+        ctx.get_current_resolve(),
+        UrlResolveKind::Symbol {
+          file: &related.origin,
+          symbol: related.get_name(),
+        },
+      );
+
+      format!(
+        r#"<a href="{}" class="link">{}</a>"#,
+        html_escape::encode_double_quoted_attribute(&href),
+        html_escape::encode_text(related.get_name())
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if items.is_empty() {
+    return None;
+  }
+
+  Some(SectionCtx::new(
+    ctx,
+    "Related",
+    SectionContentCtx::See(items),
+  ))
+}
+
 #[derive(Debug, Serialize, Clone)]
 struct SymbolCtx {
   kind: super::util::DocNodeKindCtx,
@@ -31,6 +266,8 @@ struct SymbolCtx {
   content: Vec<SymbolInnerCtx>,
   deprecated: Option<String>,
   source_href: Option<String>,
+  source_path: Option<String>,
+  declaration: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -63,6 +300,21 @@ impl SymbolGroupCtx {
 
     split_nodes.sort_keys();
 
+    let namespace_kind =
+      DocNodeKindWithDrilldown::Other(DocNodeKind::Namespace);
+    let function_properties = if split_nodes
+      .contains_key(&DocNodeKindWithDrilldown::Other(DocNodeKind::Function))
+      && split_nodes
+        .get(&namespace_kind)
+        .is_some_and(|nodes| nodes.iter().all(is_properties_namespace))
+    {
+      split_nodes
+        .shift_remove(&namespace_kind)
+        .and_then(|nodes| render_function_properties(ctx, &nodes))
+    } else {
+      None
+    };
+
     let symbols = split_nodes
       .values()
       .map(|doc_nodes| {
@@ -114,6 +366,16 @@ impl SymbolGroupCtx {
           tags.insert(Tag::Private);
         }
 
+        if doc_nodes.iter().any(is_optional_method) {
+          tags.insert(Tag::Optional);
+        }
+
+        if is_new_symbol(ctx, &doc_nodes[0]) {
+          tags.insert(Tag::New);
+        }
+
+        tags.sort();
+
         let deprecated = if all_deprecated
           && !(doc_nodes[0].kind() == DocNodeKind::Function
             && doc_nodes.len() == 1)
@@ -143,17 +405,52 @@ impl SymbolGroupCtx {
         .then(|| UsagesCtx::new(ctx, doc_nodes))
         .flatten();
 
+        let declaration = ctx.ctx.declaration_view.then(|| {
+          let declaration = crate::html::declaration::synthesize_declaration(
+            &doc_nodes.iter().map(|node| &**node).collect::<Vec<_>>(),
+          );
+
+          crate::html::jsdoc::render_markdown(
+            ctx,
+            &format!("```ts\n{declaration}\n```"),
+            true,
+          )
+        });
+
+        let mut content = SymbolInnerCtx::new(ctx, doc_nodes, name);
+
+        if doc_nodes[0].kind() == DocNodeKind::Function {
+          let sections = function_properties
+            .iter()
+            .cloned()
+            .chain(render_related_functions(ctx, &doc_nodes[0]))
+            .collect::<Vec<_>>();
+
+          if !sections.is_empty() {
+            content.push(SymbolInnerCtx::Other(SymbolContentCtx {
+              id: String::new(),
+              sections,
+              docs: None,
+            }));
+          }
+        }
+
         SymbolCtx {
           tags,
           kind: doc_nodes[0].kind_with_drilldown.into(),
           subtitle: DocBlockSubtitleCtx::new(ctx, &doc_nodes[0]),
-          content: SymbolInnerCtx::new(ctx, doc_nodes, name),
+          content,
           source_href: ctx
             .ctx
             .href_resolver
             .resolve_source(&doc_nodes[0].location),
+          source_path: ctx
+            .ctx
+            .source_path_caption
+            .then(|| source_path(ctx, &doc_nodes[0])),
           deprecated,
           usage,
+          declaration,
         }
       })
       .collect::<Vec<_>>();
@@ -287,9 +584,11 @@ impl SymbolInnerCtx {
         crate::html::jsdoc::jsdoc_body_to_html(ctx, &doc_node.js_doc, false);
 
       if doc_node.kind() != DocNodeKind::Function {
-        if let Some(examples) =
-          crate::html::jsdoc::jsdoc_examples(ctx, &doc_node.js_doc)
-        {
+        if let Some(examples) = crate::html::jsdoc::jsdoc_examples(
+          ctx,
+          &doc_node.js_doc,
+          Some(&doc_node.get_qualified_name()),
+        ) {
           sections.push(examples);
         }
       }
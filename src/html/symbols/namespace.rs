@@ -105,7 +105,7 @@ impl NamespaceNodeCtx {
     name: String,
     nodes: Vec<DocNodeWithContext>,
   ) -> Self {
-    let id = name_to_id("namespace", &name);
+    let id = name_to_id(ctx.ctx, "namespace", &name);
 
     let docs =
       crate::html::jsdoc::jsdoc_body_to_html(ctx, &nodes[0].js_doc, true);
@@ -133,17 +133,20 @@ impl NamespaceNodeCtx {
             .map(|symbol| {
               let id = match symbol.kind_with_drilldown {
                 DocNodeKindWithDrilldown::Property => name_to_id(
+                  ctx.ctx,
                   "property",
                   &symbol.drilldown_name.as_ref().unwrap().to_lowercase(),
                 ),
                 DocNodeKindWithDrilldown::Method(kind) => {
                   if matches!(kind, MethodKind::Getter | MethodKind::Setter) {
                     name_to_id(
+                      ctx.ctx,
                       "accessor",
                       &symbol.drilldown_name.as_ref().unwrap().to_lowercase(),
                     )
                   } else {
                     name_to_id(
+                      ctx.ctx,
                       "method",
                       &format!(
                         "{}_0",
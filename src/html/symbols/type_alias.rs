@@ -22,7 +22,7 @@ pub(crate) fn render_type_alias(
     .collect::<HashSet<&str>>();
   let ctx = &ctx.with_current_type_params(current_type_params);
 
-  let id = name_to_id("typeAlias", name);
+  let id = name_to_id(ctx.ctx, "typeAlias", name);
 
   let mut sections = vec![];
 
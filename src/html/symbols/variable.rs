@@ -18,7 +18,7 @@ pub(crate) fn render_variable(
     return vec![];
   };
 
-  let id = name_to_id("variable", &doc_node.get_qualified_name());
+  let id = name_to_id(ctx.ctx, "variable", &doc_node.get_qualified_name());
 
   let mut sections = vec![];
 
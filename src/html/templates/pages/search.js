@@ -9,10 +9,15 @@ const currentFile =
 const pathToRoot = "../".repeat(
   currentFile ? (currentFile.split("/").length + 1) : 0,
 );
-searchInput.removeAttribute("style");
+searchInput.classList.remove("hidden");
 
 const SEARCH_INDEX = window.DENO_DOC_SEARCH_INDEX;
 
+// kinds selected via the filter chips; all kinds are shown if empty
+const activeKinds = new Set();
+let hideDeprecated = false;
+let currentResults = [];
+
 const fuse = new Fuse(SEARCH_INDEX.nodes, {
   keys: [{
     name: "name",
@@ -74,6 +79,26 @@ searchInput.addEventListener("input", (e) => {
   debouncedSearch(val);
 });
 
+searchResultsDiv.addEventListener("click", (e) => {
+  const filter = e.target.closest(".searchFilter");
+  if (!filter) {
+    return;
+  }
+
+  const kind = filter.dataset.kind;
+  if (kind) {
+    if (activeKinds.has(kind)) {
+      activeKinds.delete(kind);
+    } else {
+      activeKinds.add(kind);
+    }
+  } else {
+    hideDeprecated = !hideDeprecated;
+  }
+
+  renderResults(currentResults);
+});
+
 function doSearch(val) {
   if (!val) {
     updateCurrentLocation(val);
@@ -107,15 +132,62 @@ function showSearchResults() {
   searchResultsDiv.style.display = "block";
 }
 
+function renderFilters(results) {
+  const kinds = new Map();
+  for (const result of results) {
+    for (const kind of result.kind) {
+      kinds.set(kind.kind, kind);
+    }
+  }
+
+  let html = `<div class="searchFilters">`;
+
+  for (const kind of kinds.values()) {
+    const active = activeKinds.has(kind.kind);
+    html +=
+      `<button type="button" class="searchFilter text-${kind.kind}${
+        active ? " active" : ""
+      }" data-kind="${kind.kind}" aria-pressed="${active}">${kind.title}</button>`;
+  }
+
+  if (results.some((result) => result.deprecated)) {
+    html +=
+      `<button type="button" class="searchFilter${
+        hideDeprecated ? " active" : ""
+      }" aria-pressed="${hideDeprecated}">Hide deprecated</button>`;
+  }
+
+  html += `</div>`;
+
+  return { html, kinds };
+}
+
 function renderResults(results) {
+  currentResults = results;
+
   if (results.length === 0) {
     searchResultsDiv.innerHTML = `<span>No result</span>`;
     return;
   }
 
-  let html = `<ul>`;
+  const filters = renderFilters(results);
+  // ignore selected kinds which are not part of the current results
+  const kinds = [...activeKinds].filter((kind) => filters.kinds.has(kind));
 
-  for (const result of results) {
+  const filteredResults = results.filter((result) =>
+    (kinds.length === 0 ||
+      result.kind.some((kind) => kinds.includes(kind.kind))) &&
+    !(hideDeprecated && result.deprecated)
+  );
+
+  if (filteredResults.length === 0) {
+    searchResultsDiv.innerHTML = `${filters.html}<span>No result</span>`;
+    return;
+  }
+
+  let html = `${filters.html}<ul>`;
+
+  for (const result of filteredResults) {
     const kind = result.kind.map((kind) => {
       return `<div class="text-${kind.kind} bg-${kind.kind}/15" title="${kind.title}">${kind.char}</div>`;
     }).join("");
@@ -13,6 +13,16 @@ document.addEventListener("click", (e) => {
   );
   if (target) {
     navigator?.clipboard?.writeText(target.dataset["copy"]);
+
+    if (!target.dataset["copied"]) {
+      const content = target.innerHTML;
+      target.dataset["copied"] = "true";
+      target.innerHTML = "Copied!";
+      setTimeout(() => {
+        target.innerHTML = content;
+        delete target.dataset["copied"];
+      }, 2000);
+    }
   }
 });
 
@@ -31,3 +41,82 @@ window.addEventListener("load", () => {
     }
   });
 });
+
+const COLLAPSED_SECTIONS_KEY = "ddocCollapsedSections";
+
+function getCollapsedSections() {
+  try {
+    return JSON.parse(localStorage.getItem(COLLAPSED_SECTIONS_KEY)) ?? {};
+  } catch {
+    return {};
+  }
+}
+
+function setSectionCollapsed(id, collapsed) {
+  const collapsedSections = getCollapsedSections();
+  if (collapsed) {
+    collapsedSections[id] = true;
+  } else {
+    delete collapsedSections[id];
+  }
+
+  try {
+    localStorage.setItem(
+      COLLAPSED_SECTIONS_KEY,
+      JSON.stringify(collapsedSections),
+    );
+  } catch {
+    // storage might be unavailable, eg. in private browsing
+  }
+}
+
+document.addEventListener("DOMContentLoaded", () => {
+  const collapsedSections = getCollapsedSections();
+
+  for (const section of document.querySelectorAll("section.section[id]")) {
+    const heading = section.querySelector(
+      ":scope > div:first-child > :is(h1, h2, h3, h4, h5, h6)",
+    );
+    if (!heading || section.children.length < 2) {
+      continue;
+    }
+
+    const button = document.createElement("button");
+    button.className = "sectionToggle";
+    button.title = "Toggle section";
+
+    const setCollapsed = (collapsed) => {
+      section.classList.toggle("collapsed", collapsed);
+      button.setAttribute("aria-expanded", String(!collapsed));
+    };
+    setCollapsed(!!collapsedSections[section.id]);
+
+    button.addEventListener("click", () => {
+      const collapsed = !section.classList.contains("collapsed");
+      setCollapsed(collapsed);
+      setSectionCollapsed(section.id, collapsed);
+    });
+
+    heading.append(button);
+  }
+});
+
+document.addEventListener("DOMContentLoaded", () => {
+  const backToTop = document.getElementById("backToTop");
+  if (!backToTop) {
+    return;
+  }
+
+  const update = () => {
+    backToTop.hidden = window.scrollY < window.innerHeight;
+  };
+  update();
+  window.addEventListener("scroll", update, { passive: true });
+
+  backToTop.addEventListener("click", () => {
+    const reducedMotion = window.matchMedia(
+      "(prefers-reduced-motion: reduce)",
+    ).matches;
+    window.scrollTo({ top: 0, behavior: reducedMotion ? "auto" : "smooth" });
+  });
+});
@@ -9,6 +9,7 @@ use crate::ts_type::TsTypeDefKind;
 use crate::ts_type_param::TsTypeParamDef;
 use deno_ast::swc::ast::MethodKind;
 use deno_ast::swc::ast::TruePlusMinus;
+use std::collections::HashSet;
 
 pub(crate) fn render_type_def_colon(
   ctx: &RenderContext,
@@ -17,6 +18,39 @@ pub(crate) fn render_type_def_colon(
   format!("<span>: {}</span>", render_type_def(ctx, def))
 }
 
+pub(crate) fn render_entry_type_def_colon(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+) -> String {
+  format!("<span>: {}</span>", render_entry_type_def(ctx, def))
+}
+
+/// Render the type of a parameter or property doc entry. If
+/// `literal_union_list` is set, a union of literals is rendered as a list,
+/// which only applies to the type of the entry itself and not to the types
+/// nested in it, eg. the type arguments of `Foo<"a" | "b">`.
+pub(crate) fn render_entry_type_def(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+) -> String {
+  let union = def
+    .union
+    .as_deref()
+    .filter(|union| ctx.ctx.literal_union_list && is_literal_union(union));
+
+  let Some(union) = union else {
+    return render_type_def(ctx, def);
+  };
+
+  let items = union
+    .iter()
+    .map(|element| format!("<span>{}</span>", render_type_def(ctx, element)))
+    .collect::<Vec<_>>()
+    .join("");
+
+  format!(r#"<span class="literalUnion">{items}</span>"#)
+}
+
 pub(crate) fn render_type_def(
   ctx: &RenderContext,
   def: &crate::ts_type::TsTypeDef,
@@ -38,6 +72,10 @@ pub(crate) fn render_type_def(
           r#"<a href="{}" class="link">{keyword}</a>"#,
           html_escape::encode_double_quoted_attribute(&href),
         )
+      } else if keyword == "never" && ctx.ctx.never_type_note {
+        format!(
+          r#"<span class="neverType" title="{NEVER_TYPE_NOTE}">never</span>"#
+        )
       } else {
         format!("<span>{keyword}</span>")
       }
@@ -46,11 +84,14 @@ pub(crate) fn render_type_def(
       let lit = def.literal.as_ref().unwrap();
 
       match lit.kind {
-        LiteralDefKind::Number
-        | LiteralDefKind::BigInt
-        | LiteralDefKind::Boolean => {
+        // the repr of a number is its value rather than its source text, so
+        // eg. `0xff` is rendered as `255`
+        LiteralDefKind::Number | LiteralDefKind::Boolean => {
           format!("<span>{}</span>", html_escape::encode_text(&def.repr))
         }
+        LiteralDefKind::BigInt => {
+          format!("<span>{}n</span>", html_escape::encode_text(&def.repr))
+        }
         LiteralDefKind::String => {
           format!("<span>{:?}</span>", html_escape::encode_text(&def.repr))
         }
@@ -83,21 +124,37 @@ pub(crate) fn render_type_def(
       let href = if ctx.contains_type_param(&type_ref.type_name) {
         Some(format!(
           "#{}",
-          name_to_id("type_param", &type_ref.type_name)
+          name_to_id(ctx.ctx, "type_param", &type_ref.type_name)
         ))
       } else {
-        ctx.lookup_symbol_href(&type_ref.type_name)
+        ctx
+          .lookup_symbol_href(&type_ref.type_name)
+          .or_else(|| enum_member_href(ctx, &type_ref.type_name))
+          .or_else(|| intrinsic_type_href(ctx, &type_ref.type_name))
       };
 
+      let title = ctx
+        .ctx
+        .qualified_type_names
+        .then(|| ctx.lookup_symbol_qualified_name(&type_ref.type_name))
+        .flatten()
+        .map(|qualified_name| {
+          format!(
+            r#" title="{}""#,
+            html_escape::encode_double_quoted_attribute(&qualified_name)
+          )
+        })
+        .unwrap_or_default();
+
       let name = if let Some(href) = href {
         format!(
-          r#"<a href="{}" class="link">{}</a>"#,
+          r#"<a href="{}" class="link"{title}>{}</a>"#,
           html_escape::encode_double_quoted_attribute(&href),
           html_escape::encode_text(&type_ref.type_name)
         )
       } else {
         format!(
-          r#"<span>{}</span>"#,
+          r#"<span{title}>{}</span>"#,
           html_escape::encode_text(&type_ref.type_name)
         )
       };
@@ -113,21 +170,47 @@ pub(crate) fn render_type_def(
       )
     }
     TsTypeDefKind::Union => {
-      type_def_join(ctx, def.union.as_ref().unwrap(), "|")
+      let union = def.union.as_ref().unwrap();
+
+      type_def_join(ctx, union, "|", TypePrecedence::Union)
     }
     TsTypeDefKind::Intersection => {
-      type_def_join(ctx, def.intersection.as_ref().unwrap(), "&")
+      let intersection = def.intersection.as_ref().unwrap();
+
+      render_branded_type(ctx, def, intersection)
+        .or_else(|| render_non_nullable_type(ctx, def, intersection))
+        .unwrap_or_else(|| {
+          type_def_join(ctx, intersection, "&", TypePrecedence::Intersection)
+        })
     }
     TsTypeDefKind::Array => {
-      format!("{}[]", render_type_def(ctx, def.array.as_ref().unwrap()))
+      format!(
+        "{}[]",
+        render_type_operand(
+          ctx,
+          def.array.as_ref().unwrap(),
+          TypePrecedence::Postfix
+        )
+      )
     }
     TsTypeDefKind::Tuple => type_def_tuple(ctx, def.tuple.as_ref().unwrap()),
     TsTypeDefKind::TypeOperator => {
       let operator = def.type_operator.as_ref().unwrap();
+      let keys = if operator.operator == "keyof" {
+        operator
+          .ts_type
+          .type_query
+          .as_ref()
+          .and_then(|query| render_keyof_typeof_keys(ctx, query))
+          .unwrap_or_default()
+      } else {
+        String::new()
+      };
+
       format!(
-        "<span>{}</span> {}",
+        "<span>{}</span> {}{keys}",
         operator.operator,
-        render_type_def(ctx, &operator.ts_type)
+        render_type_operand(ctx, &operator.ts_type, TypePrecedence::Operator)
       )
     }
     TsTypeDefKind::Parenthesized => {
@@ -140,12 +223,19 @@ pub(crate) fn render_type_def(
       format!("...{}", render_type_def(ctx, def.rest.as_ref().unwrap()))
     }
     TsTypeDefKind::Optional => {
-      render_type_def(ctx, def.optional.as_ref().unwrap())
+      format!(
+        "{}?",
+        render_type_operand(
+          ctx,
+          def.optional.as_ref().unwrap(),
+          TypePrecedence::Postfix
+        )
+      )
     }
     TsTypeDefKind::TypeQuery => {
       let query = def.type_query.as_ref().unwrap();
 
-      if let Some(href) = ctx.lookup_symbol_href(query) {
+      let name = if let Some(href) = ctx.lookup_symbol_href(query) {
         format!(
           r#"<a href="{}" class="link">{}</a>"#,
           html_escape::encode_double_quoted_attribute(&href),
@@ -153,7 +243,9 @@ pub(crate) fn render_type_def(
         )
       } else {
         format!("<span>{}</span>", html_escape::encode_text(query))
-      }
+      };
+
+      format!("<span>typeof</span> {name}")
     }
     TsTypeDefKind::This => "<span>this</span>".to_string(),
     TsTypeDefKind::FnOrConstructor => {
@@ -182,20 +274,35 @@ pub(crate) fn render_type_def(
         render_type_def(ctx, &conditional.false_type),
       )
     }
-    TsTypeDefKind::Infer => format!(
-      "<span>infer {}</span>",
-      type_param_summary(
-        ctx,
-        &def.infer.as_ref().unwrap().type_param,
-        "extends",
+    TsTypeDefKind::Infer => {
+      let type_param = &def.infer.as_ref().unwrap().type_param;
+
+      let constraint = type_param
+        .constraint
+        .as_ref()
+        .map(|constraint| {
+          format!(
+            r#"<span><span> extends </span>{}</span>"#,
+            render_type_def(ctx, constraint)
+          )
+        })
+        .unwrap_or_default();
+
+      format!(
+        r#"<span>infer <span class="inferType">{}</span>{constraint}</span>"#,
+        type_param.name,
       )
-    ),
+    }
     TsTypeDefKind::IndexedAccess => {
       let indexed_access = def.indexed_access.as_ref().unwrap();
 
       format!(
         "{}[{}]",
-        render_type_def(ctx, &indexed_access.obj_type),
+        render_type_operand(
+          ctx,
+          &indexed_access.obj_type,
+          TypePrecedence::Postfix
+        ),
         render_type_def(ctx, &indexed_access.index_type)
       )
     }
@@ -218,7 +325,7 @@ pub(crate) fn render_type_def(
         .name_type
         .as_ref()
         .map(|name_type| {
-          format!("<span> in keyof </span>{}", render_type_def(ctx, name_type))
+          format!("<span> as </span>{}", render_type_def(ctx, name_type))
         })
         .unwrap_or_default();
 
@@ -239,7 +346,7 @@ pub(crate) fn render_type_def(
         .unwrap_or_default();
 
       format!(
-        "{readonly}[{}{name_type}]{optional}{ts_type}",
+        "{{ {readonly}[{}{name_type}]{optional}{ts_type} }}",
         type_param_summary(ctx, &mapped.type_param, "in")
       )
     }
@@ -298,7 +405,7 @@ pub(crate) fn render_type_def(
           .unwrap_or_default();
 
         let name = if property.computed {
-          format!("[{}]", html_escape::encode_text(&property.name))
+          render_computed_key(ctx, &property.name, property.string_literal_key)
         } else {
           html_escape::encode_text(&property.name).to_string()
         };
@@ -328,9 +435,9 @@ pub(crate) fn render_type_def(
         let name = if method.name == "new" {
           "<span>new </span>".to_string()
         } else if method.computed {
-          format!("[{}]", method.name)
+          render_computed_key(ctx, &method.name, method.string_literal_key)
         } else {
-          method.name.clone()
+          html_escape::encode_text(&method.name).into_owned()
         };
 
         let optional = method.optional.then_some("?").unwrap_or_default();
@@ -383,7 +490,17 @@ pub(crate) fn render_type_def(
         .qualifier
         .as_ref()
         .map(|qualifier| {
-          format!("<span>.{}</span>", html_escape::encode_text(qualifier))
+          if let Some(href) =
+            ctx.lookup_import_type_href(&import_type.specifier, qualifier)
+          {
+            format!(
+              r#"<span>.</span><a href="{}" class="link">{}</a>"#,
+              html_escape::encode_double_quoted_attribute(&href),
+              html_escape::encode_text(qualifier)
+            )
+          } else {
+            format!("<span>.{}</span>", html_escape::encode_text(qualifier))
+          }
         })
         .unwrap_or_default();
 
@@ -401,15 +518,346 @@ pub(crate) fn render_type_def(
   }
 }
 
+fn is_literal_union(union: &[crate::ts_type::TsTypeDef]) -> bool {
+  union.len() > 1
+    && union.iter().all(|element| {
+      element.kind == Some(TsTypeDefKind::Literal)
+        && element
+          .literal
+          .as_ref()
+          .is_some_and(|literal| literal.kind != LiteralDefKind::Template)
+    })
+}
+
+/// Sort the members of a union for readability, removing duplicates:
+/// literals first, then named types, other types and lastly keywords such as
+/// `null` and `undefined`. Members of the same kind are sorted by their text.
+pub(crate) fn sort_union(
+  union: &[crate::ts_type::TsTypeDef],
+) -> Vec<crate::ts_type::TsTypeDef> {
+  let mut seen = HashSet::new();
+  let mut members = union
+    .iter()
+    .filter(|member| seen.insert(member.to_string()))
+    .collect::<Vec<_>>();
+
+  members.sort_by_cached_key(|member| {
+    let rank = match member.kind {
+      Some(TsTypeDefKind::Literal) => 0,
+      Some(TsTypeDefKind::TypeRef) => 1,
+      Some(TsTypeDefKind::Keyword) => 3,
+      _ => 2,
+    };
+
+    (rank, member.to_string())
+  });
+
+  members.into_iter().cloned().collect()
+}
+
+/// Paths relative to the TypeScript handbook of the documentation of the
+/// intrinsic and utility types, which have no local definition.
+const INTRINSIC_TYPES: &[(&str, &str)] = &[
+  ("Awaited", "utility-types.html#awaitedtype"),
+  ("Partial", "utility-types.html#partialtype"),
+  ("Required", "utility-types.html#requiredtype"),
+  ("Readonly", "utility-types.html#readonlytype"),
+  ("Record", "utility-types.html#recordkeys-type"),
+  ("Pick", "utility-types.html#picktype-keys"),
+  ("Omit", "utility-types.html#omittype-keys"),
+  (
+    "Exclude",
+    "utility-types.html#excludeuniontype-excludedmembers",
+  ),
+  ("Extract", "utility-types.html#extracttype-union"),
+  ("NonNullable", "utility-types.html#nonnullabletype"),
+  ("Parameters", "utility-types.html#parameterstype"),
+  (
+    "ConstructorParameters",
+    "utility-types.html#constructorparameterstype",
+  ),
+  ("ReturnType", "utility-types.html#returntypetype"),
+  ("InstanceType", "utility-types.html#instancetypetype"),
+  ("NoInfer", "utility-types.html#noinfertype"),
+  (
+    "ThisParameterType",
+    "utility-types.html#thisparametertypetype",
+  ),
+  (
+    "OmitThisParameter",
+    "utility-types.html#omitthisparametertype",
+  ),
+  ("ThisType", "utility-types.html#thistypetype"),
+  (
+    "Uppercase",
+    "2/template-literal-types.html#uppercasestringtype",
+  ),
+  (
+    "Lowercase",
+    "2/template-literal-types.html#lowercasestringtype",
+  ),
+  (
+    "Capitalize",
+    "2/template-literal-types.html#capitalizestringtype",
+  ),
+  (
+    "Uncapitalize",
+    "2/template-literal-types.html#uncapitalizestringtype",
+  ),
+];
+
+/// The href of the anchor of an enum member referenced as a type or value,
+/// eg. `Color.Red`.
+pub(crate) fn enum_member_href(
+  ctx: &RenderContext,
+  type_name: &str,
+) -> Option<String> {
+  let (enum_name, member) = type_name.rsplit_once('.')?;
+
+  let enum_node = ctx.ctx.doc_nodes.values().flatten().find(|node| {
+    node.get_qualified_name() == enum_name
+      && node.enum_def().is_some_and(|enum_def| {
+        enum_def.members.iter().any(|m| m.name == member)
+      })
+  })?;
+
+  let href = ctx.lookup_symbol_href(enum_name)?;
+
+  if ctx.ctx.single_page {
+    return Some(href);
+  }
+
+  // member ids are anchorized when the members section is rendered
+  Some(format!(
+    "{href}#{}",
+    slugify(&name_to_id(
+      ctx.ctx,
+      "enum",
+      &format!("{}_{member}", enum_node.get_name())
+    ))
+  ))
+}
+
+/// Render the keys of the object a `keyof typeof x` type refers to, each
+/// linked to its property on the page of the variable.
+fn render_keyof_typeof_keys(
+  ctx: &RenderContext,
+  query: &str,
+) -> Option<String> {
+  let variable_node = ctx.ctx.doc_nodes.values().flatten().find(|node| {
+    node.get_qualified_name() == query && node.variable_def().is_some()
+  })?;
+
+  let properties = &variable_node
+    .variable_def()?
+    .ts_type
+    .as_ref()?
+    .type_literal
+    .as_ref()?
+    .properties;
+
+  if properties.is_empty() || properties.iter().any(|prop| prop.computed) {
+    return None;
+  }
+
+  let href = ctx.lookup_symbol_href(query)?;
+
+  let keys = properties
+    .iter()
+    .map(|property| {
+      let href = if ctx.ctx.single_page {
+        href.clone()
+      } else {
+        // property ids are anchorized when the properties section is rendered
+        format!(
+          "{href}#{}",
+          slugify(&name_to_id(ctx.ctx, "property", &property.name))
+        )
+      };
+
+      format!(
+        r#"<a href="{}" class="link">"{}"</a>"#,
+        html_escape::encode_double_quoted_attribute(&href),
+        html_escape::encode_text(
+          &property.name.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("<span> | </span>");
+
+  Some(format!(
+    r#"<span class="resolvedKeys">one of {keys}</span>"#
+  ))
+}
+
+const NEVER_TYPE_NOTE: &str =
+  "No value has this type: a parameter typed never \
+  cannot be passed, and is usually an exhaustiveness check";
+
+fn intrinsic_type_href(ctx: &RenderContext, name: &str) -> Option<String> {
+  let base = ctx.ctx.intrinsic_type_docs_base.as_ref()?;
+
+  INTRINSIC_TYPES
+    .iter()
+    .find(|(intrinsic, _)| *intrinsic == name)
+    .map(|(_, path)| format!("{}/{path}", base.trim_end_matches('/')))
+}
+
+/// How tightly a type binds, from loosest to tightest, following the grammar
+/// of TypeScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TypePrecedence {
+  /// Function, constructor and conditional types.
+  Function,
+  Union,
+  Intersection,
+  /// Type operators like `keyof` and `infer` types.
+  Operator,
+  /// Array and indexed access types.
+  Postfix,
+  Primary,
+}
+
+impl TypePrecedence {
+  fn of(def: &crate::ts_type::TsTypeDef) -> Self {
+    match def.kind {
+      Some(TsTypeDefKind::FnOrConstructor | TsTypeDefKind::Conditional) => {
+        Self::Function
+      }
+      Some(TsTypeDefKind::Union) => Self::Union,
+      Some(TsTypeDefKind::Intersection) => Self::Intersection,
+      Some(TsTypeDefKind::TypeOperator | TsTypeDefKind::Infer) => {
+        Self::Operator
+      }
+      Some(TsTypeDefKind::Array | TsTypeDefKind::IndexedAccess) => {
+        Self::Postfix
+      }
+      _ => Self::Primary,
+    }
+  }
+}
+
+/// Render a type which is an operand of a type with the passed precedence,
+/// wrapping it in parentheses if it binds more loosely.
+fn render_type_operand(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+  precedence: TypePrecedence,
+) -> String {
+  if TypePrecedence::of(def) < precedence {
+    format!("({})", render_type_def(ctx, def))
+  } else {
+    render_type_def(ctx, def)
+  }
+}
+
+/// Whether the type is the brand of a nominal type, ie. a type literal which
+/// only has computed properties of type `unique symbol` or `__` prefixed
+/// properties, such as `{ readonly [brand]: unique symbol }` or
+/// `{ __brand: "UserId" }`.
+fn is_brand(def: &crate::ts_type::TsTypeDef) -> bool {
+  let Some(type_literal) = &def.type_literal else {
+    return false;
+  };
+
+  type_literal.methods.is_empty()
+    && type_literal.call_signatures.is_empty()
+    && type_literal.index_signatures.is_empty()
+    && !type_literal.properties.is_empty()
+    && type_literal.properties.iter().all(|property| {
+      (property.computed
+        && property.ts_type.as_ref().is_some_and(is_unique_symbol))
+        || property.name.starts_with("__")
+    })
+}
+
+fn is_unique_symbol(def: &crate::ts_type::TsTypeDef) -> bool {
+  def.type_operator.as_ref().is_some_and(|type_operator| {
+    type_operator.operator == "unique"
+      && type_operator.ts_type.keyword.as_deref() == Some("symbol")
+  })
+}
+
+/// Render an intersection with a brand as `Brand<Base>`, with the full type
+/// shown as a tooltip.
+fn render_branded_type(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+  intersection: &[crate::ts_type::TsTypeDef],
+) -> Option<String> {
+  let (brands, bases): (Vec<_>, Vec<_>) =
+    intersection.iter().cloned().partition(is_brand);
+
+  if brands.is_empty() || bases.is_empty() {
+    return None;
+  }
+
+  let base = if bases.len() == 1 {
+    render_type_def(ctx, &bases[0])
+  } else {
+    type_def_join(ctx, &bases, "&", TypePrecedence::Intersection)
+  };
+
+  Some(format!(
+    r#"<span class="brandedType" title="{}">Brand&lt;{base}&gt;</span>"#,
+    html_escape::encode_double_quoted_attribute(&def.to_string())
+  ))
+}
+
+/// Render the `T & {}` idiom, which excludes `null` and `undefined` from `T`
+/// and preserves literal types, as the other members of the intersection,
+/// with the idiom explained in a tooltip.
+fn render_non_nullable_type(
+  ctx: &RenderContext,
+  def: &crate::ts_type::TsTypeDef,
+  intersection: &[crate::ts_type::TsTypeDef],
+) -> Option<String> {
+  let is_empty_object = |ts_type: &crate::ts_type::TsTypeDef| {
+    ts_type.type_literal.as_ref().is_some_and(|type_literal| {
+      type_literal.constructors.is_empty()
+        && type_literal.methods.is_empty()
+        && type_literal.properties.is_empty()
+        && type_literal.call_signatures.is_empty()
+        && type_literal.index_signatures.is_empty()
+    })
+  };
+
+  let bases = intersection
+    .iter()
+    .filter(|ts_type| !is_empty_object(ts_type))
+    .cloned()
+    .collect::<Vec<_>>();
+
+  if bases.is_empty() || bases.len() == intersection.len() {
+    return None;
+  }
+
+  let base = if bases.len() == 1 {
+    render_type_def(ctx, &bases[0])
+  } else {
+    type_def_join(ctx, &bases, "&", TypePrecedence::Intersection)
+  };
+
+  Some(format!(
+    r#"<span class="nonNullableType" title="{}: {NON_NULLABLE_TYPE_NOTE}">{base}</span>"#,
+    html_escape::encode_double_quoted_attribute(&def.to_string())
+  ))
+}
+
+const NON_NULLABLE_TYPE_NOTE: &str = "intersecting with {} excludes null and \
+  undefined, and preserves literal types when inferred";
+
 fn type_def_join(
   ctx: &RenderContext,
   union: &[crate::ts_type::TsTypeDef],
   join: &str,
+  precedence: TypePrecedence,
 ) -> String {
   if union.len() <= 2 {
     let items = union
       .iter()
-      .map(|element| render_type_def(ctx, element))
+      .map(|element| render_type_operand(ctx, element, precedence))
       .collect::<Vec<String>>()
       .join(&format!("<span> {join} </span>"));
 
@@ -425,7 +873,7 @@ fn type_def_join(
         } else {
           String::new()
         },
-        render_type_def(ctx, element)
+        render_type_operand(ctx, element, precedence)
       ));
     }
 
@@ -566,7 +1014,7 @@ pub(crate) fn render_type_params(
     .collect::<std::collections::HashMap<&str, &str>>();
 
   for type_param in type_params.iter() {
-    let id = name_to_id("type_param", &type_param.name);
+    let id = name_to_id(ctx.ctx, "type_param", &type_param.name);
 
     let constraint = type_param
       .constraint
@@ -610,3 +1058,423 @@ pub(crate) fn render_type_params(
     SectionContentCtx::DocEntry(items),
   ))
 }
+
+/// Render the key of a computed property or method, eg. `[Symbol.iterator]`,
+/// linking it to the symbol the key refers to if there is one.
+fn render_computed_key(
+  ctx: &RenderContext,
+  key: &str,
+  string_literal_key: bool,
+) -> String {
+  if string_literal_key {
+    return format!(
+      "[<span>\"{}\"</span>]",
+      html_escape::encode_text(&key.replace('\\', "\\\\").replace('"', "\\\""))
+    );
+  }
+
+  let key = member_path(key);
+  let name = html_escape::encode_text(&key);
+
+  if let Some(href) = ctx.lookup_symbol_href(&key) {
+    format!(
+      r#"[<a href="{}" class="link">{name}</a>]"#,
+      html_escape::encode_double_quoted_attribute(&href)
+    )
+  } else {
+    format!("[{name}]")
+  }
+}
+
+/// Unwrap a member expression key, which is stored with each level wrapped
+/// in brackets, eg. `[[a.b].c]` for `a.b.c`.
+fn member_path(key: &str) -> String {
+  let Some(inner) = key.strip_prefix('[').and_then(|key| key.strip_suffix(']'))
+  else {
+    return key.to_string();
+  };
+
+  match inner.rsplit_once('.') {
+    Some((object, property)) => format!("{}.{property}", member_path(object)),
+    None => inner.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::html::GenerateCtx;
+  use crate::html::GenerateOptions;
+  use crate::html::HrefResolver;
+  use crate::html::UrlResolveKind;
+  use crate::ts_type::TsTypeDef;
+  use crate::Location;
+  use deno_ast::swc::ast::Decl;
+  use deno_ast::swc::ast::ModuleItem;
+  use deno_ast::swc::ast::Stmt;
+  use deno_ast::MediaType;
+  use deno_ast::ModuleSpecifier;
+  use deno_ast::ParseParams;
+
+  struct EmptyResolver;
+
+  impl HrefResolver for EmptyResolver {
+    fn resolve_path(
+      &self,
+      current: UrlResolveKind,
+      target: UrlResolveKind,
+    ) -> String {
+      crate::html::href_path_resolve(current, target)
+    }
+
+    fn resolve_global_symbol(&self, _symbol: &[String]) -> Option<String> {
+      None
+    }
+
+    fn resolve_import_href(
+      &self,
+      _symbol: &[String],
+      _src: &str,
+    ) -> Option<String> {
+      None
+    }
+
+    fn resolve_usage(
+      &self,
+      _current_resolve: UrlResolveKind,
+    ) -> Option<String> {
+      None
+    }
+
+    fn resolve_source(&self, _location: &Location) -> Option<String> {
+      None
+    }
+
+    fn resolve_external_jsdoc_module(
+      &self,
+      _module: &str,
+      _symbol: Option<&str>,
+    ) -> Option<(String, String)> {
+      None
+    }
+  }
+
+  fn generate_ctx() -> GenerateCtx {
+    GenerateCtx::new(
+      GenerateOptions::new(std::rc::Rc::new(EmptyResolver)),
+      Default::default(),
+      Default::default(),
+      Default::default(),
+    )
+    .unwrap()
+  }
+
+  fn parse_type(ts_type: &str) -> TsTypeDef {
+    let parsed_source = deno_ast::parse_module(ParseParams {
+      specifier: ModuleSpecifier::parse("file:///mod.ts").unwrap(),
+      text: format!("type T = {ts_type};").into(),
+      media_type: MediaType::TypeScript,
+      capture_tokens: false,
+      scope_analysis: false,
+      maybe_syntax: None,
+    })
+    .unwrap();
+
+    let ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(type_alias))) =
+      &parsed_source.module().body[0]
+    else {
+      unreachable!()
+    };
+
+    TsTypeDef::new(&parsed_source, &type_alias.type_ann)
+  }
+
+  fn render(ctx: &GenerateCtx, ts_type: &str) -> String {
+    render_def(ctx, &parse_type(ts_type))
+  }
+
+  fn render_def(ctx: &GenerateCtx, ts_type: &TsTypeDef) -> String {
+    let render_ctx = RenderContext::new(ctx, &[], UrlResolveKind::AllSymbols);
+    let rendered = render_type_def(&render_ctx, ts_type);
+
+    // strip the markup to only compare the displayed text
+    let mut out = String::new();
+    let mut in_tag = false;
+    for char in rendered.chars() {
+      match char {
+        '<' => in_tag = true,
+        '>' if in_tag => in_tag = false,
+        _ if !in_tag => out.push(char),
+        _ => {}
+      }
+    }
+    html_escape::decode_html_entities(&out).into_owned()
+  }
+
+  #[test]
+  fn unique_symbol() {
+    let ctx = generate_ctx();
+
+    assert_eq!(render(&ctx, "unique symbol"), "unique symbol");
+    assert_eq!(render(&ctx, "symbol"), "symbol");
+  }
+
+  #[test]
+  fn intrinsic_types() {
+    let mut ctx = generate_ctx();
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("NoInfer<T>")),
+      "<span>NoInfer</span>&lt;<span>T</span>&gt;"
+    );
+
+    ctx.intrinsic_type_docs_base =
+      Some("https://www.typescriptlang.org/docs/handbook/".to_string());
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("NoInfer<T>")),
+      r#"<a href="https://www.typescriptlang.org/docs/handbook/utility-types.html#noinfertype" class="link">NoInfer</a>&lt;<span>T</span>&gt;"#
+    );
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("Uppercase<T>")),
+      r#"<a href="https://www.typescriptlang.org/docs/handbook/2/template-literal-types.html#uppercasestringtype" class="link">Uppercase</a>&lt;<span>T</span>&gt;"#
+    );
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("Unknown")),
+      "<span>Unknown</span>"
+    );
+  }
+
+  #[test]
+  fn literal_union_list() {
+    let mut ctx = generate_ctx();
+    ctx.literal_union_list = true;
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+
+    assert_eq!(
+      render_entry_type_def(&render_ctx, &parse_type(r#""a" | "b""#)),
+      r#"<span class="literalUnion"><span><span>"a"</span></span><span><span>"b"</span></span></span>"#
+    );
+
+    // nested and inline types are never rendered as a list
+    assert!(!render_entry_type_def(
+      &render_ctx,
+      &parse_type(r#"Foo<"a" | "b">"#)
+    )
+    .contains("literalUnion"));
+    assert!(!render_type_def(&render_ctx, &parse_type(r#""a" | "b""#))
+      .contains("literalUnion"));
+    assert_eq!(render(&ctx, r#""a" | "b""#), r#""a" | "b""#);
+
+    ctx.literal_union_list = false;
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert!(
+      !render_entry_type_def(&render_ctx, &parse_type(r#""a" | "b""#))
+        .contains("literalUnion")
+    );
+  }
+
+  #[test]
+  fn never_type_note() {
+    let mut ctx = generate_ctx();
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("never")),
+      "<span>never</span>"
+    );
+
+    ctx.never_type_note = true;
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("never")),
+      format!(
+        r#"<span class="neverType" title="{NEVER_TYPE_NOTE}">never</span>"#
+      )
+    );
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("string")),
+      "<span>string</span>"
+    );
+  }
+
+  #[test]
+  fn sort_union() {
+    let ctx = generate_ctx();
+    let union =
+      parse_type(r#"undefined | Foo | "b" | string[] | Bar | "a" | Foo"#);
+
+    let sorted = TsTypeDef {
+      kind: Some(TsTypeDefKind::Union),
+      union: Some(super::sort_union(union.union.as_ref().unwrap())),
+      ..Default::default()
+    };
+    assert_eq!(
+      render_def(&ctx, &sorted),
+      r#""a" | "b" | Bar | Foo | string[] | undefined"#
+    );
+  }
+
+  #[test]
+  fn type_param_defaults() {
+    let ctx = generate_ctx();
+
+    assert_eq!(
+      render(
+        &ctx,
+        "new <T extends object = Record<string, unknown>>() => T"
+      ),
+      "new <T extends object = Record<string, unknown>>() => T"
+    );
+    assert_eq!(
+      render(&ctx, "{ get<T = string>(key: string): T }"),
+      "{ get<T = string>(key: string): T;  }"
+    );
+  }
+
+  #[test]
+  fn numeric_literals() {
+    let ctx = generate_ctx();
+
+    assert_eq!(render(&ctx, "-1 | 0 | 1"), "-1 | 0 | 1");
+    assert_eq!(render(&ctx, "1n | -1n"), "1n | -1n");
+    assert_eq!(render(&ctx, "-0.5 | 1.25"), "-0.5 | 1.25");
+  }
+
+  #[test]
+  fn infer_types() {
+    let ctx = generate_ctx();
+
+    assert_eq!(
+      render(&ctx, "T extends Promise<infer U> ? U : never"),
+      "T extends Promise<infer U> ? U : never"
+    );
+    assert_eq!(
+      render(
+        &ctx,
+        "T extends [infer H extends string, ...unknown[]] ? H : T"
+      ),
+      "T extends [infer H extends string, ...unknown[]] ? H : T"
+    );
+
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert!(render_type_def(
+      &render_ctx,
+      &parse_type("T extends Array<infer U> ? U : never")
+    )
+    .contains(r#"infer <span class="inferType">U</span>"#));
+  }
+
+  #[test]
+  fn type_precedence() {
+    let ctx = generate_ctx();
+
+    assert_eq!(render(&ctx, "A & (B | C)"), "A & (B | C)");
+    assert_eq!(render(&ctx, "(keyof T)[]"), "(keyof T)[]");
+
+    // synthesized types have no parenthesized nodes
+    let intersection = TsTypeDef {
+      kind: Some(TsTypeDefKind::Intersection),
+      intersection: Some(vec![parse_type("A"), parse_type("B | C")]),
+      ..Default::default()
+    };
+    assert_eq!(render_def(&ctx, &intersection), "A & (B | C)");
+
+    let union = TsTypeDef {
+      kind: Some(TsTypeDefKind::Union),
+      union: Some(vec![parse_type("() => void"), parse_type("A & B")]),
+      ..Default::default()
+    };
+    assert_eq!(render_def(&ctx, &union), "(() => void) | A & B");
+
+    let array = TsTypeDef {
+      kind: Some(TsTypeDefKind::Array),
+      array: Some(Box::new(union)),
+      ..Default::default()
+    };
+    assert_eq!(render_def(&ctx, &array), "((() => void) | A & B)[]");
+  }
+
+  #[test]
+  fn optional_and_rest_elements() {
+    let ctx = generate_ctx();
+
+    assert_eq!(
+      render(&ctx, "(cb: (a?: number) => void) => void"),
+      "(cb: (a?: number) => void) => void"
+    );
+    assert_eq!(
+      render(&ctx, "[number?, ...string[]]"),
+      "[number?, ...string[]]"
+    );
+    assert_eq!(
+      render(&ctx, "[a?: number, ...rest: string[]]"),
+      "[number?, ...string[]]"
+    );
+    assert_eq!(
+      render(
+        &ctx,
+        "(...args: [a: string, b?: (string | number)]) => void"
+      ),
+      "(...args: [string, (string | number)?]) => void"
+    );
+  }
+
+  #[test]
+  fn index_signatures_and_computed_keys() {
+    let ctx = generate_ctx();
+
+    assert_eq!(
+      render(
+        &ctx,
+        "{ readonly [key: string]: Record<string, number>; [Symbol.iterator](): void; [\"a<b\"]: 1; [0]: 2; [\"foo\"]: 3; [a.b.c]: 4 }"
+      ),
+      "{ readonly [key: string]: Record<string, number>; [\"a<b\"]: 1; [0]: 2; [\"foo\"]: 3; [a.b.c]: 4; [Symbol.iterator](): void;  }"
+    );
+  }
+
+  #[test]
+  fn branded_types() {
+    let ctx = generate_ctx();
+
+    assert_eq!(
+      render(&ctx, "string & { readonly __brand: \"UserId\" }"),
+      "Brand<string>"
+    );
+    assert_eq!(
+      render(&ctx, "{ readonly [brand]: unique symbol } & number"),
+      "Brand<number>"
+    );
+    assert_eq!(render(&ctx, "A & { b: string }"), "A & { b: string;  }");
+    assert_eq!(
+      render(&ctx, "A & { [key]: string }"),
+      "A & { [key]: string;  }"
+    );
+    assert_eq!(
+      render(&ctx, "A & { [Symbol.iterator](): void }"),
+      "A & { [Symbol.iterator](): void;  }"
+    );
+
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("string & { __brand: 1 }")),
+      r#"<span class="brandedType" title="string &amp; { __brand: 1; }">Brand&lt;<span>string</span>&gt;</span>"#
+    );
+  }
+
+  #[test]
+  fn non_nullable_types() {
+    let ctx = generate_ctx();
+
+    assert_eq!(render(&ctx, "T & {}"), "T");
+    assert_eq!(render(&ctx, "{} & (A | B)"), "(A | B)");
+    assert_eq!(render(&ctx, "{} & {}"), "{  } & {  }");
+
+    let render_ctx = RenderContext::new(&ctx, &[], UrlResolveKind::AllSymbols);
+    assert_eq!(
+      render_type_def(&render_ctx, &parse_type("string & {}")),
+      format!(
+        r#"<span class="nonNullableType" title="string &amp; {{ }}: {NON_NULLABLE_TYPE_NOTE}"><span>string</span></span>"#
+      )
+    );
+  }
+}
@@ -131,6 +131,20 @@ pub fn usage_to_md(
   format!("```typescript\n{usage}\n```")
 }
 
+fn resolve_import_specifier(
+  import_specifier: &str,
+  current_resolve: UrlResolveKind,
+) -> String {
+  match current_resolve.get_file() {
+    Some(file) if !file.is_main => format!(
+      "{}/{}",
+      import_specifier.trim_end_matches('/'),
+      file.display_name()
+    ),
+    _ => import_specifier.to_string(),
+  }
+}
+
 fn get_identifier_for_file(ctx: &RenderContext) -> String {
   let maybe_idenfitier =
     if let Some(file) = ctx.get_current_resolve().get_file() {
@@ -174,6 +188,7 @@ struct UsageCtx {
 pub struct UsagesCtx {
   usages: Vec<UsageCtx>,
   composed: bool,
+  nonce: Option<String>,
 }
 
 impl UsagesCtx {
@@ -189,10 +204,14 @@ impl UsagesCtx {
       return None;
     }
 
-    let url = ctx
-      .ctx
-      .href_resolver
-      .resolve_usage(ctx.get_current_resolve())?;
+    let url = if let Some(import_specifier) = &ctx.ctx.import_specifier {
+      resolve_import_specifier(import_specifier, ctx.get_current_resolve())
+    } else {
+      ctx
+        .ctx
+        .href_resolver
+        .resolve_usage(ctx.get_current_resolve())?
+    };
 
     if let Some(usage_composer) = &ctx.ctx.usage_composer {
       let usages = usage_composer(ctx, doc_nodes, url);
@@ -200,7 +219,7 @@ impl UsagesCtx {
       let usages = usages
         .into_iter()
         .map(|(entry, content)| UsageCtx {
-          additional_css: render_css_for_usage(&entry.name),
+          additional_css: ctx.ctx.css_asset(&render_css_for_usage(&entry.name)),
           name: entry.name,
           icon: entry.icon,
           content: crate::html::jsdoc::render_markdown(ctx, &content, true),
@@ -213,6 +232,7 @@ impl UsagesCtx {
         Some(UsagesCtx {
           usages,
           composed: true,
+          nonce: ctx.ctx.csp_nonce.clone(),
         })
       }
     } else {
@@ -228,6 +248,7 @@ impl UsagesCtx {
           additional_css: "".to_string(),
         }],
         composed: false,
+        nonce: None,
       })
     }
   }
@@ -4,6 +4,7 @@ use crate::html::usage::UsagesCtx;
 use crate::html::DocNodeKindWithDrilldown;
 use crate::html::DocNodeWithContext;
 use crate::html::FileMode;
+use crate::html::GenerateCtx;
 use crate::html::RenderContext;
 use crate::html::ShortPath;
 use crate::js_doc::JsDoc;
@@ -24,13 +25,45 @@ lazy_static! {
     regex::Regex::new(r"\s*\* ?|\.").unwrap();
 }
 
-pub(crate) fn name_to_id(kind: &str, name: &str) -> String {
+/// The id of the anchor of an item, eg. `function_foo_0`, generated by the
+/// `anchor_id_generator` if one is set and it returns an id.
+pub(crate) fn name_to_id(ctx: &GenerateCtx, kind: &str, name: &str) -> String {
+  if let Some(id) = ctx
+    .anchor_id_generator
+    .as_ref()
+    .and_then(|anchor_id_generator| anchor_id_generator(kind, name))
+  {
+    return id;
+  }
+
   format!(
     "{kind}_{}",
     html_escape::encode_safe(&TARGET_RE.replace_all(name, "_"))
   )
 }
 
+/// The id of a module's section when rendering in single page mode.
+pub(crate) fn single_page_file_id(
+  ctx: &GenerateCtx,
+  file: &ShortPath,
+) -> String {
+  name_to_id(ctx, "module", &slugify(file.display_name()))
+}
+
+/// The id of a symbol when rendering in single page mode. Symbols are
+/// prefixed with their module as multiple modules can export the same name.
+pub(crate) fn single_page_symbol_id(
+  ctx: &GenerateCtx,
+  file: &ShortPath,
+  symbol: &str,
+) -> String {
+  format!(
+    "{}__{}",
+    single_page_file_id(ctx, file),
+    name_to_id(ctx, "symbol", symbol)
+  )
+}
+
 /// A container to hold a list of symbols with their namespaces:
 ///
 /// ["setTimeout"]
@@ -426,6 +459,8 @@ pub enum SectionContentCtx {
   IndexSignature(Vec<super::symbols::class::IndexSignatureCtx>),
   NamespaceSection(Vec<super::namespace::NamespaceNodeCtx>),
   See(Vec<String>),
+  CompactParams(Vec<super::symbols::function::CompactParamCtx>),
+  Function(super::symbols::function::FunctionCtx),
   Empty,
 }
 
@@ -455,6 +490,7 @@ impl SectionHeaderCtx {
           MarkdownToHTMLOptions {
             title_only: true,
             no_toc: false,
+            line_numbers_start: None,
           },
         )
       });
@@ -479,6 +515,7 @@ impl SectionHeaderCtx {
 pub struct SectionCtx {
   pub header: SectionHeaderCtx,
   pub content: SectionContentCtx,
+  pub heading_level: u8,
 }
 
 impl SectionCtx {
@@ -533,6 +570,12 @@ impl SectionCtx {
         }
       }
       SectionContentCtx::See(_) => {}
+      SectionContentCtx::CompactParams(params) => {
+        for param in params {
+          param.id = render_context.toc.anchorize(&param.id);
+        }
+      }
+      SectionContentCtx::Function(_) => {}
       SectionContentCtx::Empty => {}
     }
 
@@ -544,11 +587,15 @@ impl SectionCtx {
         doc: None,
       },
       content,
+      heading_level: render_context.ctx.heading_level,
     }
   }
 }
 
-#[derive(Debug, Serialize, Clone, Eq, PartialEq, Hash)]
+/// A tag shown next to a symbol or doc entry. Tags are always rendered in the
+/// order in which the variants are declared here, regardless of the order in
+/// which they were collected.
+#[derive(Debug, Serialize, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case", tag = "kind", content = "value")]
 pub enum Tag {
   New,
@@ -599,6 +646,9 @@ pub struct DocEntryCtx {
   tags: IndexSet<Tag>,
   js_doc: Option<String>,
   source_href: Option<String>,
+  /// Whether the entry is rendered less prominently, eg. for internal
+  /// parameters.
+  pub dimmed: bool,
 }
 
 impl DocEntryCtx {
@@ -611,10 +661,12 @@ impl DocEntryCtx {
     name: Option<String>,
     name_href: Option<String>,
     content: &str,
-    tags: IndexSet<Tag>,
+    mut tags: IndexSet<Tag>,
     jsdoc: Option<&str>,
     location: &crate::Location,
   ) -> Self {
+    tags.sort();
+
     let maybe_jsdoc =
       jsdoc.map(|doc| crate::html::jsdoc::render_markdown(ctx, doc, true));
     let source_href = ctx.ctx.href_resolver.resolve_source(location);
@@ -628,6 +680,7 @@ impl DocEntryCtx {
       tags,
       js_doc: maybe_jsdoc,
       source_href,
+      dimmed: false,
     }
   }
 }
@@ -642,6 +695,37 @@ pub(crate) fn all_deprecated(nodes: &[&DocNodeWithContext]) -> bool {
   })
 }
 
+/// Compare two dates or versions, eg. `2024-05-01` or `v1.10.0`, segment by
+/// segment. Numeric segments are compared by their value.
+pub(crate) fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+  fn segments(version: &str) -> impl Iterator<Item = &str> {
+    version
+      .strip_prefix(['v', 'V'])
+      .unwrap_or(version)
+      .split(|c: char| !c.is_ascii_alphanumeric())
+      .filter(|segment| !segment.is_empty())
+  }
+
+  let mut a = segments(a);
+  let mut b = segments(b);
+
+  loop {
+    let ordering = match (a.next(), b.next()) {
+      (None, None) => return std::cmp::Ordering::Equal,
+      (None, Some(_)) => std::cmp::Ordering::Less,
+      (Some(_), None) => std::cmp::Ordering::Greater,
+      (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+      },
+    };
+
+    if ordering.is_ne() {
+      return ordering;
+    }
+  }
+}
+
 pub fn qualify_drilldown_name(
   parent_name: &str,
   drilldown_name: &str,
@@ -6,6 +6,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::params::ts_fn_param_to_param_def;
+use crate::ts_type::is_string_literal_key;
 use crate::ts_type::CallSignatureDef;
 use crate::ts_type::ConstructorDef;
 use crate::ts_type::IndexSignatureDef;
@@ -120,6 +121,10 @@ pub fn get_doc_for_ts_interface_decl(
             js_doc: method_js_doc,
             location: get_location(parsed_source, ts_method_sig.start()),
             computed: ts_method_sig.computed,
+            string_literal_key: is_string_literal_key(
+              ts_method_sig.computed,
+              &ts_method_sig.key,
+            ),
             optional: ts_method_sig.optional,
             params,
             return_type: maybe_return_type,
@@ -145,6 +150,10 @@ pub fn get_doc_for_ts_interface_decl(
             js_doc: method_js_doc,
             location: get_location(parsed_source, ts_getter_sig.start()),
             computed: ts_getter_sig.computed,
+            string_literal_key: is_string_literal_key(
+              ts_getter_sig.computed,
+              &ts_getter_sig.key,
+            ),
             optional: false,
             params: vec![],
             return_type: maybe_return_type,
@@ -169,6 +178,10 @@ pub fn get_doc_for_ts_interface_decl(
             js_doc: method_js_doc,
             location: get_location(parsed_source, ts_setter_sig.start()),
             computed: ts_setter_sig.computed,
+            string_literal_key: is_string_literal_key(
+              ts_setter_sig.computed,
+              &ts_setter_sig.key,
+            ),
             optional: false,
             params,
             return_type: None,
@@ -199,6 +212,10 @@ pub fn get_doc_for_ts_interface_decl(
             ts_type,
             readonly: ts_prop_sig.readonly,
             computed: ts_prop_sig.computed,
+            string_literal_key: is_string_literal_key(
+              ts_prop_sig.computed,
+              &ts_prop_sig.key,
+            ),
             optional: ts_prop_sig.optional,
             type_params,
           };
@@ -7,7 +7,7 @@ use serde::Serialize;
 lazy_static! {
   static ref JS_DOC_TAG_RE: Regex = Regex::new(r"(?s)^\s*@(\S+)").unwrap();
   /// @tag
-  static ref JS_DOC_TAG_WITHOUT_VALUE_RE: Regex = Regex::new(r"^\s*@(constructor|class|ignore|internal|public|private|protected|readonly|experimental)").unwrap();
+  static ref JS_DOC_TAG_WITHOUT_VALUE_RE: Regex = Regex::new(r"^\s*@(constructor|class|ignore|internal|public|private|protected|readonly|experimental)\b").unwrap();
   /// @tag maybe_value
   static ref JS_DOC_TAG_WITH_MAYBE_VALUE_RE: Regex = Regex::new(r"(?s)^\s*@(deprecated|module)(?:\s+(.+))?").unwrap();
   /// @tag value
@@ -23,8 +23,11 @@ lazy_static! {
     r"(?s)^\s*@(?:param|arg(?:ument)?)(?:\s+\{(?P<type>[^}]+)\})?\s+(?:(?:\[(?P<nameWithDefault>[a-zA-Z_$]\S*?)(?:\s*=\s*(?P<default>[^]]+))?\])|(?P<name>[a-zA-Z_$]\S*))(?:\s+(?P<doc>.+))?"
   )
   .unwrap();
+  /// @narrows name to type maybe_value
+  /// @narrows name to {type} maybe_value
+  static ref JS_DOC_TAG_NARROWS_RE: Regex = Regex::new(r"(?s)^\s*@narrows\s+([a-zA-Z_$]\S*)\s+to\s+(?:\{([^}]+)\}|(\S+))(?:\s+(.+))?").unwrap();
   /// @tag {maybe_type} maybe_value
-  static ref JS_DOC_TAG_WITH_MAYBE_TYPE_AND_MAYBE_VALUE_RE: Regex = Regex::new(r"(?s)^\s*@(returns?|throws|exception)(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
+  static ref JS_DOC_TAG_WITH_MAYBE_TYPE_AND_MAYBE_VALUE_RE: Regex = Regex::new(r"(?s)^\s*@(returns?|throws|exception|yields?)(?:\s+\{([^}]+)\})?(?:\s+(.+))?").unwrap();
   /// @tag {maybe_type} value
   static ref JS_DOC_TAG_WITH_TYPE_AND_MAYBE_VALUE_RE: Regex = Regex::new(r"(?s)^\s*@(enum|extends|augments|this|type|default)\s+\{([^}]+)\}(?:\s+(.+))?").unwrap();
 }
@@ -187,6 +190,15 @@ pub enum JsDocTag {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     name: Option<Box<str>>,
   },
+  /// `@narrows name to type comment` or `@narrows name to {type} comment`,
+  /// documenting that the function narrows the parameter at runtime
+  Narrows {
+    name: Box<str>,
+    #[serde(rename = "type")]
+    type_ref: Box<str>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<Box<str>>,
+  },
   /// `@param`, `@arg` or `argument`, in format of `@param {type} name comment`
   /// or `@param {type} [name=default] comment`
   /// or `@param {type} [name] comment`
@@ -274,6 +286,13 @@ pub enum JsDocTag {
   Since {
     doc: Box<str>,
   },
+  /// `@yield {type} comment` or `@yields {type} comment`
+  Yields {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    type_ref: Option<Box<str>>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    doc: Option<Box<str>>,
+  },
   Unsupported {
     value: Box<str>,
   },
@@ -361,6 +380,15 @@ impl From<String> for JsDocTag {
         "since" => Self::Since { doc },
         _ => unreachable!("kind unexpected: {}", kind),
       }
+    } else if let Some(caps) = JS_DOC_TAG_NARROWS_RE.captures(&value) {
+      let name = caps.get(1).unwrap().as_str().into();
+      let type_ref = caps.get(2).or(caps.get(3)).unwrap().as_str().into();
+      let doc = caps.get(4).map(|m| m.as_str().into());
+      Self::Narrows {
+        name,
+        type_ref,
+        doc,
+      }
     } else if let Some(caps) = JS_DOC_TAG_PARAM_RE.captures(&value) {
       let name_with_maybe_default = caps.name("nameWithDefault");
       let name = caps
@@ -388,6 +416,7 @@ impl From<String> for JsDocTag {
       match kind {
         "return" | "returns" => Self::Return { type_ref, doc },
         "throws" | "exception" => Self::Throws { type_ref, doc },
+        "yield" | "yields" => Self::Yields { type_ref, doc },
         _ => unreachable!("kind unexpected: {}", kind),
       }
     } else {
@@ -890,6 +919,78 @@ const a = "a";
     );
   }
 
+  #[test]
+  fn test_js_doc_tag_yields() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@yields {number} the next id".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "yields",
+          "type": "number",
+          "doc": "the next id",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@yield the next id".to_string()))
+        .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "yields",
+          "doc": "the next id",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_narrows() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@narrows value to {string | number} when true".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "narrows",
+          "name": "value",
+          "type": "string | number",
+          "doc": "when true",
+        }]
+      })
+    );
+    assert_eq!(
+      serde_json::to_value(JsDoc::from("@narrows value to Date".to_string()))
+        .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "narrows",
+          "name": "value",
+          "type": "Date",
+        }]
+      })
+    );
+  }
+
+  #[test]
+  fn test_js_doc_tag_private_remarks() {
+    assert_eq!(
+      serde_json::to_value(JsDoc::from(
+        "@privateRemarks not a private symbol".to_string()
+      ))
+      .unwrap(),
+      json!({
+        "tags": [{
+          "kind": "unsupported",
+          "value": "@privateRemarks not a private symbol",
+        }]
+      })
+    );
+  }
+
   #[test]
   fn test_js_doc_tag_returns() {
     assert_eq!(
@@ -28,6 +28,14 @@ pub enum ParamPatternDef {
   Assign {
     left: Box<ParamDef>,
     right: String,
+    /// The `as` or `satisfies` clause of the default value, which is not
+    /// part of `right`.
+    #[serde(
+      rename = "rightType",
+      skip_serializing_if = "Option::is_none",
+      default
+    )]
+    right_type: Option<AssignTypeDef>,
   },
   Identifier {
     name: String,
@@ -50,6 +58,10 @@ pub struct ParamDef {
   #[serde(skip_serializing_if = "<[_]>::is_empty", default)]
   pub decorators: Box<[DecoratorDef]>,
   pub ts_type: Option<TsTypeDef>,
+  /// A comment following the type annotation, eg. `px` in
+  /// `x: number /* px */`.
+  #[serde(skip_serializing_if = "Option::is_none", default)]
+  pub type_comment: Option<String>,
 }
 
 impl Display for ParamDef {
@@ -117,6 +129,16 @@ impl Display for ParamDef {
   }
 }
 
+/// The type a default value is asserted or checked to have, eg. `Mode` in
+/// `mode = "fast" satisfies Mode`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignTypeDef {
+  /// Either `as` or `satisfies`.
+  pub operator: String,
+  pub ts_type: TsTypeDef,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
@@ -142,6 +164,27 @@ impl Display for ObjectPatPropDef {
   }
 }
 
+/// The comment directly following a type annotation, on the same line.
+fn type_comment(
+  parsed_source: &ParsedSource,
+  type_ann: Option<&deno_ast::swc::ast::TsTypeAnn>,
+) -> Option<String> {
+  let type_ann = type_ann?;
+  let comments = parsed_source
+    .comments()
+    .get_trailing(type_ann.type_ann.end())?;
+
+  let text = comments
+    .iter()
+    .filter(|comment| !comment.text.starts_with('*'))
+    .map(|comment| comment.text.trim())
+    .filter(|text| !text.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  (!text.is_empty()).then_some(text)
+}
+
 pub fn ident_to_param_def(
   parsed_source: &ParsedSource,
   ident: &deno_ast::swc::ast::BindingIdent,
@@ -150,6 +193,7 @@ pub fn ident_to_param_def(
     .type_ann
     .as_deref()
     .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+  let type_comment = type_comment(parsed_source, ident.type_ann.as_deref());
 
   ParamDef {
     pattern: ParamPatternDef::Identifier {
@@ -158,6 +202,7 @@ pub fn ident_to_param_def(
     },
     decorators: Box::new([]),
     ts_type,
+    type_comment,
   }
 }
 
@@ -169,6 +214,7 @@ fn rest_pat_to_param_def(
     .type_ann
     .as_deref()
     .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+  let type_comment = type_comment(parsed_source, rest_pat.type_ann.as_deref());
 
   ParamDef {
     pattern: ParamPatternDef::Rest {
@@ -176,6 +222,7 @@ fn rest_pat_to_param_def(
     },
     decorators: Box::new([]),
     ts_type,
+    type_comment,
   }
 }
 
@@ -211,6 +258,8 @@ fn object_pat_to_param_def(
     .type_ann
     .as_deref()
     .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+  let type_comment =
+    type_comment(parsed_source, object_pat.type_ann.as_deref());
 
   ParamDef {
     pattern: ParamPatternDef::Object {
@@ -219,6 +268,7 @@ fn object_pat_to_param_def(
     },
     decorators: Box::new([]),
     ts_type,
+    type_comment,
   }
 }
 
@@ -235,6 +285,7 @@ fn array_pat_to_param_def(
     .type_ann
     .as_deref()
     .map(|type_ann| TsTypeDef::new(parsed_source, &type_ann.type_ann));
+  let type_comment = type_comment(parsed_source, array_pat.type_ann.as_deref());
 
   ParamDef {
     pattern: ParamPatternDef::Array {
@@ -243,6 +294,7 @@ fn array_pat_to_param_def(
     },
     decorators: Box::new([]),
     ts_type,
+    type_comment,
   }
 }
 
@@ -260,13 +312,45 @@ pub fn assign_pat_to_param_def(
     );
   }
 
+  let (right, right_type) = match &*assign_pat.right {
+    deno_ast::swc::ast::Expr::TsSatisfies(satisfies) => (
+      satisfies
+        .expr
+        .text_fast(parsed_source.text_info_lazy())
+        .to_string(),
+      Some(AssignTypeDef {
+        operator: "satisfies".to_string(),
+        ts_type: TsTypeDef::new(parsed_source, &satisfies.type_ann),
+      }),
+    ),
+    deno_ast::swc::ast::Expr::TsConstAssertion(const_assertion) => (
+      const_assertion
+        .text_fast(parsed_source.text_info_lazy())
+        .to_string(),
+      None,
+    ),
+    deno_ast::swc::ast::Expr::TsAs(ts_as) => (
+      ts_as
+        .expr
+        .text_fast(parsed_source.text_info_lazy())
+        .to_string(),
+      Some(AssignTypeDef {
+        operator: "as".to_string(),
+        ts_type: TsTypeDef::new(parsed_source, &ts_as.type_ann),
+      }),
+    ),
+    right => (crate::interface::expr_to_name(right), None),
+  };
+
   ParamDef {
     pattern: ParamPatternDef::Assign {
       left: Box::new(left),
-      right: crate::interface::expr_to_name(&assign_pat.right),
+      right,
+      right_type,
     },
     decorators: Box::new([]),
     ts_type: None,
+    type_comment: None,
   }
 }
 
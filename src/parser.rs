@@ -1412,6 +1412,7 @@ fn parse_json_module_type(value: &serde_json::Value) -> TsTypeDef {
             params: Vec::new(),
             readonly: false,
             computed: false,
+            string_literal_key: false,
             optional: false,
             type_params: Box::new([]),
             location: Default::default(),
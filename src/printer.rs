@@ -277,6 +277,21 @@ impl<'a> DocPrinter<'a> {
         writeln!(w, "{}@{}", Indent(indent), colors::magenta("module"))?;
         self.format_jsdoc_tag_maybe_doc(w, name, indent)
       }
+      JsDocTag::Narrows {
+        name,
+        type_ref,
+        doc,
+      } => {
+        writeln!(
+          w,
+          "{}@{} {} to {{{}}}",
+          Indent(indent),
+          colors::magenta("narrows"),
+          colors::bold(name),
+          colors::italic_cyan(type_ref)
+        )?;
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
       JsDocTag::Param {
         name,
         type_ref,
@@ -332,6 +347,15 @@ impl<'a> DocPrinter<'a> {
         }
         self.format_jsdoc_tag_maybe_doc(w, doc, indent)
       }
+      JsDocTag::Yields { type_ref, doc } => {
+        write!(w, "{}@{}", Indent(indent), colors::magenta("yields"))?;
+        if let Some(type_ref) = type_ref {
+          writeln!(w, " {{{}}}", colors::italic_cyan(type_ref))?;
+        } else {
+          writeln!(w)?;
+        }
+        self.format_jsdoc_tag_maybe_doc(w, doc, indent)
+      }
       JsDocTag::Tags { tags } => {
         writeln!(
           w,
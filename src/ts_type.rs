@@ -32,6 +32,12 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+/// Whether a computed key is a string literal, which `expr_to_name` renders
+/// without its quotes.
+pub(crate) fn is_string_literal_key(computed: bool, key: &Expr) -> bool {
+  computed && matches!(key, Expr::Lit(Lit::Str(_)))
+}
+
 impl TsTypeDef {
   fn ts_lit_type(parsed_source: &ParsedSource, other: &TsLitType) -> Self {
     match &other.lit {
@@ -65,7 +71,31 @@ impl TsTypeDef {
     let type_defs = other
       .elem_types
       .iter()
-      .map(|type_box| TsTypeDef::new(parsed_source, &type_box.ty))
+      .map(|element| {
+        let ts_type = TsTypeDef::new(parsed_source, &element.ty);
+
+        // the markers of labeled elements, eg. `a?: number`, are on the label
+        match &element.label {
+          Some(Pat::Rest(_)) if ts_type.kind != Some(TsTypeDefKind::Rest) => {
+            TsTypeDef {
+              rest: Some(Box::new(ts_type)),
+              kind: Some(TsTypeDefKind::Rest),
+              ..Default::default()
+            }
+          }
+          Some(Pat::Ident(ident))
+            if ident.optional
+              && ts_type.kind != Some(TsTypeDefKind::Optional) =>
+          {
+            TsTypeDef {
+              optional: Some(Box::new(ts_type)),
+              kind: Some(TsTypeDefKind::Optional),
+              ..Default::default()
+            }
+          }
+          _ => ts_type,
+        }
+      })
       .collect::<Vec<_>>();
 
     TsTypeDef {
@@ -373,6 +403,10 @@ impl TsTypeDef {
               location: get_location(parsed_source, ts_method_sig.start()),
               params,
               computed: ts_method_sig.computed,
+              string_literal_key: is_string_literal_key(
+                ts_method_sig.computed,
+                &ts_method_sig.key,
+              ),
               optional: ts_method_sig.optional,
               return_type: maybe_return_type,
               type_params,
@@ -397,6 +431,10 @@ impl TsTypeDef {
               location: get_location(parsed_source, ts_getter_sig.start()),
               params: vec![],
               computed: ts_getter_sig.computed,
+              string_literal_key: is_string_literal_key(
+                ts_getter_sig.computed,
+                &ts_getter_sig.key,
+              ),
               optional: false,
               return_type: maybe_return_type,
               type_params: Box::new([]),
@@ -422,6 +460,10 @@ impl TsTypeDef {
               location: get_location(parsed_source, ts_setter_sig.start()),
               params,
               computed: ts_setter_sig.computed,
+              string_literal_key: is_string_literal_key(
+                ts_setter_sig.computed,
+                &ts_setter_sig.key,
+              ),
               optional: false,
               return_type: None,
               type_params: Box::new([]),
@@ -450,6 +492,10 @@ impl TsTypeDef {
               ts_type,
               readonly: ts_prop_sig.readonly,
               computed: ts_prop_sig.computed,
+              string_literal_key: is_string_literal_key(
+                ts_prop_sig.computed,
+                &ts_prop_sig.key,
+              ),
               optional: ts_prop_sig.optional,
               type_params,
             };
@@ -951,6 +997,10 @@ pub struct MethodDef {
   pub params: Vec<ParamDef>,
   #[serde(skip_serializing_if = "is_false", default)]
   pub computed: bool,
+  /// Whether the computed key is a string literal, eg. `["a-b"]`, whose
+  /// quotes are not part of `name`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub string_literal_key: bool,
   pub optional: bool,
   pub return_type: Option<TsTypeDef>,
   pub type_params: Box<[TsTypeParamDef]>,
@@ -1006,6 +1056,10 @@ pub struct PropertyDef {
   #[serde(skip_serializing_if = "is_false", default)]
   pub readonly: bool,
   pub computed: bool,
+  /// Whether the computed key is a string literal, eg. `["a-b"]`, whose
+  /// quotes are not part of `name`.
+  #[serde(skip_serializing_if = "is_false", default)]
+  pub string_literal_key: bool,
   pub optional: bool,
   pub ts_type: Option<TsTypeDef>,
   pub type_params: Box<[TsTypeParamDef]>,
@@ -1811,6 +1865,7 @@ fn infer_ts_type_from_obj_inner(
             params: vec![],
             readonly: false,
             computed: false,
+            string_literal_key: false,
             optional: false,
             ts_type: None,
             type_params: Box::new([]),
@@ -1824,6 +1879,7 @@ fn infer_ts_type_from_obj_inner(
             params: vec![],
             readonly: false,
             computed: kv.key.is_computed(),
+            string_literal_key: false,
             optional: false,
             ts_type: infer_ts_type_from_expr(parsed_source, &kv.value, false),
             type_params: Box::new([]),
@@ -1844,6 +1900,7 @@ fn infer_ts_type_from_obj_inner(
             location: get_location(parsed_source, getter.start()),
             params: vec![],
             computed,
+            string_literal_key: false,
             optional: false,
             return_type,
             type_params: Box::new([]),
@@ -1860,6 +1917,7 @@ fn infer_ts_type_from_obj_inner(
             location: get_location(parsed_source, setter.start()),
             params: vec![param],
             computed,
+            string_literal_key: false,
             optional: false,
             return_type: None,
             type_params: Box::new([]),
@@ -1889,6 +1947,7 @@ fn infer_ts_type_from_obj_inner(
             location: get_location(parsed_source, method.start()),
             params,
             computed,
+            string_literal_key: false,
             optional: false,
             return_type,
             type_params,
@@ -2087,7 +2146,11 @@ impl Display for TsTypeDef {
             colors::yellow(&literal.number.unwrap().to_string())
           ),
           LiteralDefKind::BigInt => {
-            write!(f, "{}", colors::yellow(&literal.string.as_ref().unwrap()))
+            write!(
+              f,
+              "{}",
+              colors::yellow(&format!("{}n", literal.string.as_ref().unwrap()))
+            )
           }
         }
       }
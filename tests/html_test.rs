@@ -10,12 +10,15 @@ use deno_graph::source::LoadFuture;
 use deno_graph::source::LoadOptions;
 use deno_graph::source::LoadResponse;
 use deno_graph::source::Loader;
+use deno_graph::source::MemoryLoader;
+use deno_graph::source::Source;
 use deno_graph::BuildOptions;
 use deno_graph::CapturingModuleAnalyzer;
 use deno_graph::GraphKind;
 use deno_graph::ModuleGraph;
 use futures::future;
 use indexmap::IndexMap;
+use std::collections::HashMap;
 use std::fs;
 use std::rc::Rc;
 
@@ -140,20 +143,67 @@ async fn get_files(subpath: &str) -> IndexMap<ModuleSpecifier, Vec<DocNode>> {
   doc_nodes_by_url
 }
 
+/// Generate the pages of a single module `file:///mod.ts` with the passed
+/// source.
+async fn generate_source(
+  options: GenerateOptions,
+  source: &str,
+) -> HashMap<String, String> {
+  let specifier = ModuleSpecifier::parse("file:///mod.ts").unwrap();
+  let loader = MemoryLoader::new(
+    vec![(
+      specifier.as_str(),
+      Source::Module {
+        specifier: specifier.as_str(),
+        maybe_headers: None,
+        content: source,
+      },
+    )],
+    vec![],
+  );
+  let analyzer = CapturingModuleAnalyzer::default();
+  let mut graph = ModuleGraph::new(GraphKind::TypesOnly);
+  graph
+    .build(
+      vec![specifier.clone()],
+      &loader,
+      BuildOptions {
+        module_analyzer: &analyzer,
+        ..Default::default()
+      },
+    )
+    .await;
+
+  let nodes = DocParser::new(
+    &graph,
+    &analyzer,
+    DocParserOptions {
+      diagnostics: false,
+      private: false,
+    },
+  )
+  .unwrap()
+  .parse_with_reexports(&specifier)
+  .unwrap();
+
+  generate(options, IndexMap::from([(specifier, nodes)])).unwrap()
+}
+
+/// The markup of the doc entry with the passed id, up to the next doc entry.
+fn doc_entry<'a>(page: &'a str, id: &str) -> &'a str {
+  let start = page
+    .find(&format!(r#"docEntry" id="{id}""#))
+    .unwrap_or_else(|| panic!("no doc entry {id}"));
+  let rest = &page[start + 1..];
+  let end = rest.find(r#"docEntry" id=""#).unwrap_or(rest.len());
+
+  &rest[..end]
+}
+
 #[tokio::test]
 async fn html_doc_files() {
   let files = generate(
-    GenerateOptions {
-      package_name: None,
-      main_entrypoint: None,
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
-      rewrite_map: None,
-      category_docs: None,
-      disable_search: false,
-      symbol_redirect_map: None,
-      default_symbol_map: None,
-    },
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
     get_files("single").await,
   )
   .unwrap();
@@ -216,15 +266,9 @@ async fn html_doc_files_rewrite() {
 
   let files = generate(
     GenerateOptions {
-      package_name: None,
       main_entrypoint: Some(main_specifier),
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
       rewrite_map: Some(rewrite_map),
-      category_docs: None,
-      disable_search: false,
-      symbol_redirect_map: None,
-      default_symbol_map: None,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
     },
     get_files("multiple").await,
   )
@@ -299,6 +343,185 @@ async fn html_doc_files_rewrite() {
   }
 }
 
+#[tokio::test]
+async fn html_doc_files_single_page() {
+  let files = generate(
+    GenerateOptions {
+      single_page: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    get_files("single").await,
+  )
+  .unwrap();
+
+  let mut file_names = files.keys().collect::<Vec<_>>();
+  file_names.sort();
+
+  assert_eq!(
+    file_names,
+    ["./index.html", "page.css", "reset.css", "styles.css",]
+  );
+
+  insta::assert_snapshot!(files.get("./index.html").unwrap());
+}
+
+#[tokio::test]
+async fn html_doc_files_inject() {
+  let files = generate(
+    GenerateOptions {
+      inject_head: Some(r#"<meta name="injected-head">"#.to_string()),
+      inject_body: Some(r#"<div id="injected-body"></div>"#.to_string()),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    get_files("single").await,
+  )
+  .unwrap();
+
+  for file in ["./index.html", "./all_symbols.html", "./~/Foo.html"] {
+    let page = files.get(file).unwrap();
+    let (head, body) = page.split_once("</head>").unwrap();
+
+    assert!(head.contains(r#"<meta name="injected-head">"#), "{file}");
+    assert!(
+      body
+        .trim_start()
+        .strip_prefix("<body>")
+        .unwrap()
+        .trim_start()
+        .starts_with(r#"<div id="injected-body"></div>"#),
+      "{file}"
+    );
+  }
+
+  let files = generate(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    get_files("single").await,
+  )
+  .unwrap();
+  assert!(!files.get("./index.html").unwrap().contains("injected"));
+}
+
+#[tokio::test]
+async fn html_doc_files_reexport_stubs() {
+  let files = generate(
+    GenerateOptions {
+      symbol_redirect_map: Some(IndexMap::from([(
+        ".".to_string(),
+        IndexMap::from([(
+          "Foo".to_string(),
+          "https://example.com/~/Foo.html".to_string(),
+        )]),
+      )])),
+      reexport_stubs: true,
+      renamed_symbols: Some(IndexMap::from([(
+        ".".to_string(),
+        IndexMap::from([
+          ("OldBar".to_string(), "Bar".to_string()),
+          ("Bar".to_string(), "Foo".to_string()),
+        ]),
+      )])),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    get_files("single").await,
+  )
+  .unwrap();
+
+  let stub = files.get("./~/Foo.html").unwrap();
+  assert!(stub.starts_with(r#"<meta http-equiv="refresh""#));
+  assert!(stub.contains("example.com&#x2F;~&#x2F;Foo.html"));
+  assert!(!files.contains_key("./~/Foo.prototype.html"));
+  assert!(files.contains_key("./~/Bar.html"));
+
+  let renamed = files.get("./~/OldBar.html").unwrap();
+  assert!(renamed.starts_with(r#"<meta http-equiv="refresh""#));
+  assert!(renamed.contains("~&#x2F;Bar.html"));
+  assert!(!files.get("./~/Bar.html").unwrap().contains("http-equiv"));
+
+  let search_index = files.get("search_index.js").unwrap();
+  assert!(!search_index.contains(r#""name":"Foo""#));
+  assert!(search_index.contains(r#""name":"Bar""#));
+}
+
+#[tokio::test]
+async fn html_doc_files_streaming() {
+  for fixture in ["single", "multiple"] {
+    let options = GenerateOptions {
+      symbol_list: true,
+      llms_txt: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    };
+
+    let mut streamed = std::collections::HashMap::new();
+    generate_streaming(
+      options.clone(),
+      get_files(fixture).await,
+      |path, content| {
+        assert!(streamed.insert(path, content).is_none());
+        Ok(())
+      },
+    )
+    .unwrap();
+
+    let files = generate(options, get_files(fixture).await).unwrap();
+
+    assert_eq!(streamed, files);
+  }
+}
+
+#[tokio::test]
+async fn symbol_list() {
+  let files = generate(
+    GenerateOptions {
+      symbol_list: true,
+      llms_txt: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    get_files("multiple").await,
+  )
+  .unwrap();
+
+  let symbol_list: serde_json::Value =
+    serde_json::from_str(files.get("symbols.json").unwrap()).unwrap();
+  let symbols = symbol_list["symbols"].as_array().unwrap();
+
+  assert_eq!(symbols.len(), 12);
+  assert_eq!(
+    symbols[3],
+    serde_json::json!({
+      "name": "Baz",
+      "kind": "typeAlias",
+      "file": "a.ts",
+      "signature": null,
+      "description": null,
+      "url": "./a.ts/~/Baz.html",
+    })
+  );
+  assert_eq!(
+    symbols[4],
+    serde_json::json!({
+      "name": "Foo",
+      "kind": "class",
+      "file": "a.ts",
+      "signature": null,
+      "description": "some Foo docs Bar",
+      "url": "./a.ts/~/Foo.html",
+    })
+  );
+
+  let qaz = symbols
+    .iter()
+    .find(|symbol| symbol["name"] == "qaz")
+    .unwrap();
+  assert_eq!(qaz["kind"], "function");
+  assert_eq!(qaz["signature"], "(a: string)");
+
+  let llms_txt = files.get("llms.txt").unwrap();
+  assert!(llms_txt.starts_with("# Documentation\n"));
+  assert!(llms_txt
+    .contains("- [Foo](./a.ts/~/Foo.html): some Foo docs Bar `class Foo`\n"));
+  assert!(llms_txt.contains("`function qaz(a: string)`\n"));
+}
+
 #[tokio::test]
 async fn symbol_group() {
   let multiple_dir = std::env::current_dir()
@@ -321,17 +544,11 @@ async fn symbol_group() {
 
   let ctx = GenerateCtx::new(
     GenerateOptions {
-      package_name: None,
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
       rewrite_map: Some(rewrite_map),
-      category_docs: None,
-      disable_search: false,
-      symbol_redirect_map: None,
-      default_symbol_map: None,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
     },
     None,
     Default::default(),
@@ -365,9 +582,8 @@ async fn symbol_group() {
             let html_head_ctx = pages::HtmlHeadCtx::new(
               &root,
               Some(&symbol_group_ctx.name),
-              ctx.package_name.as_ref(),
+              &ctx,
               Some(short_path),
-              false,
             );
 
             Some(pages::SymbolPageCtx {
@@ -376,7 +592,9 @@ async fn symbol_group() {
               breadcrumbs_ctx,
               toc_ctx,
               disable_search: false,
+              no_script: false,
               categories_panel,
+              experimental_banner: None,
             })
           }
           SymbolPage::Redirect { .. } => None,
@@ -410,17 +628,11 @@ async fn symbol_search() {
 
   let ctx = GenerateCtx::new(
     GenerateOptions {
-      package_name: None,
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
       rewrite_map: Some(rewrite_map),
-      category_docs: None,
-      disable_search: false,
-      symbol_redirect_map: None,
-      default_symbol_map: None,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
     },
     None,
     Default::default(),
@@ -455,17 +667,11 @@ async fn module_doc() {
 
   let ctx = GenerateCtx::new(
     GenerateOptions {
-      package_name: None,
       main_entrypoint: Some(
         ModuleSpecifier::from_file_path(multiple_dir.join("a.ts")).unwrap(),
       ),
-      href_resolver: Rc::new(EmptyResolver {}),
-      usage_composer: None,
       rewrite_map: Some(rewrite_map),
-      category_docs: None,
-      disable_search: false,
-      symbol_redirect_map: None,
-      default_symbol_map: None,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
     },
     None,
     FileMode::Single,
@@ -485,3 +691,1081 @@ async fn module_doc() {
 
   insta::assert_json_snapshot!(module_docs);
 }
+
+#[tokio::test]
+async fn type_predicates() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export interface Foo {}
+export function isString(value: unknown): value is string { return true; }
+export function assertString(value: unknown): asserts value is string {}
+export function assertOk(value: unknown): asserts value {}
+export function isFoo(this: unknown): this is Foo { return true; }
+"#,
+  )
+  .await;
+
+  assert!(files.get("./~/isString.html").unwrap().contains(
+    r##"<a href="#function_isString_0_parameters_value" class="link">value</a> is <span>string</span>"##
+  ));
+  assert!(files.get("./~/assertString.html").unwrap().contains(
+    r##"<span>asserts </span><a href="#function_assertString_0_parameters_value" class="link">value</a> is <span>string</span>"##
+  ));
+  assert!(files.get("./~/assertOk.html").unwrap().contains(
+    r##"<span>asserts </span><a href="#function_assertOk_0_parameters_value" class="link">value</a></span>"##
+  ));
+
+  // `this` is not a parameter which can be linked to
+  let is_foo = files.get("./~/isFoo.html").unwrap();
+  assert!(is_foo.contains(
+    r#"<span>this</span> is <a href="../././~/Foo.html" class="link">Foo</a>"#
+  ));
+  assert!(!is_foo.contains("parameters_this\" class=\"link\""));
+}
+
+#[tokio::test]
+async fn experimental_banner() {
+  let tagged = r#"
+/**
+ * @module
+ * @experimental
+ */
+export function foo() {}
+"#;
+  let untagged = "export function foo() {}";
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), tagged)
+      .await;
+  assert!(files.get("./~/foo.html").unwrap().contains(
+    r#"<div class="experimentalBanner"><div class="markdown"><p>This module is experimental."#
+  ));
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), untagged)
+      .await;
+  assert!(!files
+    .get("./~/foo.html")
+    .unwrap()
+    .contains("experimentalBanner"));
+
+  let files = generate_source(
+    GenerateOptions {
+      experimental_modules: Some(vec![ModuleSpecifier::parse(
+        "file:///mod.ts",
+      )
+      .unwrap()]),
+      experimental_banner: Some("Use **at your own risk**.".to_string()),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    untagged,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+  assert!(page.contains(r#"<div class="experimentalBanner">"#));
+  assert!(page.contains("Use <strong>at your own risk</strong>."));
+}
+
+#[tokio::test]
+async fn collapse_param_types() {
+  let source = r#"
+export interface Options {}
+export function foo(
+  small: string,
+  big: { a: string; b: number; c: boolean },
+  named: Options & { extra: Record<string, number> },
+  alias: Options,
+) {}
+"#;
+
+  let files = generate_source(
+    GenerateOptions {
+      collapse_param_types_over: Some(20),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    source,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  assert_eq!(
+    page.matches(r#"<details class="collapsedType">"#).count(),
+    2
+  );
+  assert!(page.contains(
+    r#"<details class="collapsedType"><summary>{ … }<span>show full type</span></summary>{ a<span>: <span>string</span></span>;"#
+  ));
+  assert!(page.contains(
+    r#"<details class="collapsedType"><summary>…<span>show full type</span></summary><span><a href="../././~/Options.html" class="link">Options</a>"#
+  ));
+  // types within the threshold are rendered inline
+  assert!(page.contains(
+    r#"<span>: <a href="../././~/Options.html" class="link">Options</a></span>"#
+  ));
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), source)
+      .await;
+  assert!(!files.get("./~/foo.html").unwrap().contains("collapsedType"));
+}
+
+#[tokio::test]
+async fn implementation_param_docs() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export function foo(a: string): string;
+/**
+ * @param b the docs of b on the overload
+ */
+export function foo(a: number, b: number): number;
+/**
+ * @param a the docs of a
+ * @param b the docs of b
+ * @param c unrelated
+ */
+export function foo(a: unknown, b?: number): unknown {
+  return a;
+}
+"#,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  assert!(
+    doc_entry(page, "function_foo_0_parameters_a").contains("the docs of a")
+  );
+  assert!(
+    doc_entry(page, "function_foo_1_parameters_a").contains("the docs of a")
+  );
+  // the docs of the overload take precedence
+  let b = doc_entry(page, "function_foo_1_parameters_b");
+  assert!(b.contains("the docs of b on the overload"));
+  assert!(!b.contains("the docs of b<"));
+  assert!(!page.contains("unrelated"));
+}
+
+#[tokio::test]
+async fn related_functions() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+/** @category Math */
+export function add(a: number, b: number): number;
+/** @category Math */
+export function add(a: string, b: string): string;
+export function add(a: any, b: any): any { return a + b; }
+/** @category Math */
+export function sub(a: number, b: number): number { return a - b; }
+/** @category Math */
+export const pi = 3.14;
+/** @category Strings */
+export function trim(s: string): string { return s; }
+export function other(): void {}
+"#,
+  )
+  .await;
+
+  let sub = files.get("./~/sub.html").unwrap();
+  let related = &sub[sub.find(r#"id="related""#).unwrap()..];
+  let related = &related[..related.find("</section>").unwrap()];
+  // overloads are listed once, and only functions of the same category
+  assert!(related.contains(
+    r#"<ul class="see"><li><a href="../././~/add.html" class="link">add</a></li> </ul>"#
+  ));
+  assert!(!related.contains("pi.html"));
+  assert!(!related.contains("trim.html"));
+  assert!(!related.contains("sub.html"));
+
+  assert!(files
+    .get("./~/add.html")
+    .unwrap()
+    .contains(r#"id="related""#));
+  assert!(!files
+    .get("./~/trim.html")
+    .unwrap()
+    .contains(r#"id="related""#));
+  assert!(!files
+    .get("./~/other.html")
+    .unwrap()
+    .contains(r#"id="related""#));
+}
+
+#[tokio::test]
+async fn enum_member_links() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export enum Color { Red, Green }
+export function paint(color: Color.Red, any: Color, missing: Color.Blue) {}
+"#,
+  )
+  .await;
+  let page = files.get("./~/paint.html").unwrap();
+
+  assert!(doc_entry(page, "function_paint_0_parameters_color").contains(
+    r#"<a href="../././~/Color.html#enum_color_red" class="link">Color.Red</a>"#
+  ));
+  assert!(doc_entry(page, "function_paint_0_parameters_any")
+    .contains(r#"<a href="../././~/Color.html" class="link">Color</a>"#));
+  // members which do not exist are not linked
+  assert!(doc_entry(page, "function_paint_0_parameters_missing")
+    .contains("<span>Color.Blue</span>"));
+
+  // the anchor which is linked to
+  assert!(files
+    .get("./~/Color.html")
+    .unwrap()
+    .contains(r#"id="enum_color_red""#));
+}
+
+#[tokio::test]
+async fn constructor_overloads() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export class Foo {
+  /** From a string. */
+  constructor(a: string);
+  /** From a number. */
+  constructor(a: number, b?: boolean);
+  constructor(a: unknown, b?: boolean) {}
+}
+export class Bar {
+  constructor(a: string) {}
+}
+"#,
+  )
+  .await;
+
+  // overloaded constructors are rendered like overloaded functions, without
+  // the implementation signature
+  let foo = files.get("./~/Foo.html").unwrap();
+  assert!(foo.contains(r#"<section class="section" id="constructors">"#));
+  assert!(foo.contains(r#"<div class="scroll-mt-16" id="function_Foo_0">"#));
+  assert!(foo.contains(r#"<div class="scroll-mt-16" id="function_Foo_1">"#));
+  assert!(!foo.contains(r#"id="function_Foo_2""#));
+  assert!(foo.contains("<p>From a string.</p>"));
+  assert!(foo.contains("<p>From a number.</p>"));
+  assert!(foo.contains(r#"id="function_foo_1_parameters_b""#));
+  assert!(!foo.contains(r#"id="constructor_0""#));
+
+  // a single constructor is still rendered as a doc entry
+  let bar = files.get("./~/Bar.html").unwrap();
+  assert!(bar.contains(r#"docEntry" id="constructor_0""#));
+  assert!(!bar.contains(r#"id="function_Bar_0""#));
+}
+
+#[tokio::test]
+async fn accessor_pairs() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export class Foo {
+  get size(): number { return 0; }
+  set size(value: number | string) {}
+  get same(): string { return ""; }
+  set same(value: string) {}
+  get onlyGet(): boolean { return true; }
+}
+"#,
+  )
+  .await;
+  let page = files.get("./~/Foo.html").unwrap();
+
+  let size = doc_entry(page, "accessor_size");
+  assert!(size.contains(
+    r#"<span>: <span>number</span></span><span class="writableType">writable as <span><span>number</span><span> | </span><span>string</span></span></span>"#
+  ));
+  assert_eq!(page.matches(r#"docEntry" id="accessor_size""#).count(), 1);
+
+  let same = doc_entry(page, "accessor_same");
+  assert!(same.contains("<span>: <span>string</span></span>"));
+  assert!(!same.contains("writableType"));
+
+  let only_get = doc_entry(page, "accessor_onlyget");
+  assert!(only_get.contains(">readonly</div>"));
+  assert!(!only_get.contains("writableType"));
+}
+
+#[tokio::test]
+async fn expand_tuple_rest() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+/** The arguments. */
+export type Args = [name: string, count?: number];
+export function foo(...args: [string, number?, ...boolean[]]) {}
+export function bar(...args: Args) {}
+export function baz(...args: string[]) {}
+"#,
+  )
+  .await;
+
+  let foo = files.get("./~/foo.html").unwrap();
+  assert!(
+    foo.contains("<span>args[0]<span>: <span>string</span></span></span>,")
+  );
+  assert!(
+    foo.contains("<span>args[1]?<span>: <span>number</span></span></span>,")
+  );
+  assert!(foo.contains(
+    "<span><span>...args[2]</span><span>: <span>boolean</span>[]</span></span>,"
+  ));
+
+  let bar = files.get("./~/bar.html").unwrap();
+  assert!(
+    bar.contains("<span>args[0]<span>: <span>string</span></span></span>,")
+  );
+  assert!(
+    bar.contains("<span>args[1]?<span>: <span>number</span></span></span>,")
+  );
+  assert!(!bar.contains("args[2]"));
+  assert!(bar.contains(r#"<a href="../././~/Args.html" class="link">Args</a>"#));
+
+  let baz = files.get("./~/baz.html").unwrap();
+  assert!(baz.contains(
+    "<span><span>...args</span><span>: <span>string</span>[]</span></span>"
+  ));
+  assert!(!baz.contains("args[0]"));
+}
+
+#[tokio::test]
+async fn deprecated_overloads_last() {
+  let source = r#"
+/** @deprecated use the string overload */
+export function foo(a: number): void;
+export function foo(a: string): void;
+/** @deprecated */
+export function foo(a: boolean): void;
+export function foo(a: bigint): void;
+export function foo(a: unknown) {}
+"#;
+
+  let overload_positions = |page: &str| {
+    let mut ids = (0..4)
+      .map(|i| {
+        let id = format!(r#"class="scroll-mt-16" id="function_foo_{i}""#);
+        (page.find(&id).unwrap(), i)
+      })
+      .collect::<Vec<_>>();
+    ids.sort();
+    ids.into_iter().map(|(_, i)| i).collect::<Vec<_>>()
+  };
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), source)
+      .await;
+  let page = files.get("./~/foo.html").unwrap();
+  assert_eq!(overload_positions(page), vec![0, 1, 2, 3]);
+
+  let files = generate_source(
+    GenerateOptions {
+      deprecated_overloads_last: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    source,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+  assert_eq!(overload_positions(page), vec![1, 3, 0, 2]);
+}
+
+#[tokio::test]
+async fn function_properties() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export function foo() {}
+foo.bar = 1;
+
+export function baz() {}
+export namespace baz {
+  export const x = "a";
+}
+
+export function qux() {}
+export namespace qux {
+  export class C {}
+}
+"#,
+  )
+  .await;
+
+  let foo = files.get("./~/foo.html").unwrap();
+  assert!(foo.contains(r#"<section class="section" id="properties">"#));
+  assert!(doc_entry(foo, "property_foo_bar")
+    .contains(r#"<span>: <span>1</span></span>"#));
+  assert!(!foo.contains("namespaceSection"));
+
+  let baz = files.get("./~/baz.html").unwrap();
+  assert!(baz.contains(r#"<section class="section" id="properties">"#));
+  assert!(doc_entry(baz, "property_baz_x")
+    .contains(r#"<span>: <span>"a"</span></span>"#));
+  assert!(!baz.contains("namespaceSection"));
+
+  let qux = files.get("./~/qux.html").unwrap();
+  assert!(!qux.contains(r#"id="properties""#));
+  assert!(qux.contains("namespaceSection"));
+}
+
+#[tokio::test]
+async fn type_literal_keys() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export type Key = string;
+export interface Value {}
+export const sym: unique symbol = Symbol();
+export namespace a {
+  export namespace b {
+    export const c: unique symbol = Symbol();
+  }
+}
+export function foo(
+  a: {
+    [key: Key]: Value;
+    [sym]: Value;
+    ["a<b"]: Value;
+    ["sym"]: Value;
+    [a.b.c]: Value;
+  },
+) {}
+"#,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  assert!(page.contains(
+    r#"{ [<span><span>key<span>: <a href="../././~/Key.html" class="link">Key</a></span></span></span>]<span>: <a href="../././~/Value.html" class="link">Value</a></span>; "#
+  ));
+  assert!(page
+    .contains(r#"[<a href="../././~/sym.html" class="link">sym</a>]<span>: "#));
+  assert!(page.contains(r#"[<span>"a&lt;b"</span>]<span>: "#));
+  assert!(page.contains(r#"[<span>"sym"</span>]<span>: "#));
+  assert!(page.contains(
+    r#"[<a href="../././~/a.b.c.html" class="link">a.b.c</a>]<span>: "#
+  ));
+}
+
+#[tokio::test]
+async fn csp_nonce() {
+  let files = generate_source(
+    GenerateOptions {
+      csp_nonce: Some("r4nd0m".to_string()),
+      usage_composer: Some(Rc::new(|_ctx, _doc_nodes, url| {
+        IndexMap::from([(
+          UsageComposerEntry {
+            name: "Deno".to_string(),
+            icon: None,
+          },
+          format!("```ts\nimport {{ foo }} from \"{url}\";\n```"),
+        )])
+      })),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    "export function foo() {}",
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  assert_eq!(page.matches("<script").count(), 4);
+  assert_eq!(page.matches(r#"<script nonce="r4nd0m" src="#).count(), 4);
+  assert!(page.contains(r#"<style scoped nonce="r4nd0m">"#));
+  assert!(!page.contains("style=\""));
+}
+
+#[tokio::test]
+async fn keyof_typeof_keys() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export const modes = { fast: 1, "a\"<b": 2 };
+export function foo(mode: keyof typeof modes) {}
+"#,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  assert!(page.contains(
+    r#"<span class="resolvedKeys">one of <a href="../././~/modes.html#property_fast" class="link">"fast"</a><span> | </span><a href="../././~/modes.html#property_aquotltb" class="link">"a\"&lt;b"</a></span>"#
+  ));
+}
+
+#[tokio::test]
+async fn merge_overloads() {
+  let files = generate_source(
+    GenerateOptions {
+      merge_overloads: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    r#"
+export function foo(a: string, b: number): void;
+export function foo(a: boolean | string, b: number): void;
+export function foo(a: (x: number) => void, b: number): void;
+export function foo(a: unknown, b: number) {}
+
+export function bar(a: string, b: string): void;
+export function bar(a: number, b: number): void;
+export function bar(a: unknown, b: unknown) {}
+
+/** One. */
+export function baz(a: string): void;
+/** Two. */
+export function baz(a: number): void;
+export function baz(a: unknown) {}
+"#,
+  )
+  .await;
+  let overload_count = |page: &str| {
+    page
+      .matches(r#"class="scroll-mt-16" id="function_"#)
+      .count()
+  };
+
+  // unions are flattened and deduplicated, function types parenthesized
+  let foo = files.get("./~/foo.html").unwrap();
+  assert_eq!(overload_count(foo), 1);
+  assert!(foo.contains(
+    r#"<span>a<span>: <div class="ml-indent"><span><span>string</span></span><br /><span><span> | </span><span>boolean</span></span><br /><span><span> | </span>((<span><span>x<span>: <span>number</span></span></span></span>) =&gt; <span>void</span>)</span></div></span></span>,"#
+  ));
+  assert!(foo.contains(
+    r#"<span class="mergedOverloads">merges 3 overloads differing in the type of a</span>"#
+  ));
+
+  // more than one parameter differs
+  let bar = files.get("./~/bar.html").unwrap();
+  assert_eq!(overload_count(bar), 2);
+  assert!(!bar.contains("mergedOverloads"));
+
+  // the docs differ
+  let baz = files.get("./~/baz.html").unwrap();
+  assert_eq!(overload_count(baz), 2);
+  assert!(!baz.contains("mergedOverloads"));
+}
+
+#[tokio::test]
+async fn overload_order_with_return_type_groups() {
+  let files = generate_source(
+    GenerateOptions {
+      group_overloads_by_return_type: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    r#"
+/** @overloadOrder 3 */
+export function foo(a: boolean): string;
+/** @overloadOrder 1 */
+export function foo(a: string): string;
+/** @overloadOrder 2 */
+export function foo(a: number): number;
+export function foo(a: unknown): unknown {}
+"#,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  let mut ids = (0..3)
+    .map(|i| {
+      let id = format!(r#"class="scroll-mt-16" id="function_foo_{i}""#);
+      (page.find(&id).unwrap(), i)
+    })
+    .collect::<Vec<_>>();
+  ids.sort();
+  assert_eq!(
+    ids.into_iter().map(|(_, i)| i).collect::<Vec<_>>(),
+    vec![1, 2, 0]
+  );
+
+  // the overloads returning `string` are no longer adjacent, so their
+  // heading is repeated
+  assert_eq!(
+    page
+      .matches(r#"<h3 class="overloadGroup">Returns <code><span>string</span></code></h3>"#)
+      .count(),
+    2
+  );
+  assert_eq!(page.matches(r#"<h3 class="overloadGroup">"#).count(), 3);
+}
+
+#[tokio::test]
+async fn default_value_types() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export type Mode = "fast" | "slow";
+export function run(mode = "fast" satisfies Mode, text = "read as text") {}
+export function make<T extends object>(value: T = {} as T) {}
+"#,
+  )
+  .await;
+
+  let run = files.get("./~/run.html").unwrap();
+  assert!(run.contains(
+    r#"= </span>"fast"<span> satisfies </span><a href="../././~/Mode.html" class="link">Mode</a></span>"#
+  ));
+  // an `as` inside a string is part of the expression
+  assert!(run.contains("= </span>read as text</span>"));
+
+  assert!(files.get("./~/make.html").unwrap().contains(
+    r##"= </span>{}<span> as </span><a href="#type_param_T" class="link">T</a></span>"##
+  ));
+}
+
+#[tokio::test]
+async fn single_page_custom_module_ids() {
+  let files = generate_source(
+    GenerateOptions {
+      single_page: true,
+      anchor_id_generator: Some(Rc::new(|kind, name| {
+        (kind == "module").then(|| format!("file-{name}"))
+      })),
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    r#"
+export interface Foo {}
+export function foo(value: Foo) {}
+"#,
+  )
+  .await;
+
+  let page = files.get("./index.html").unwrap();
+  assert!(page.contains(r##"id="file-default__symbol_foo""##));
+  // links to other symbols are not prefixed with the id of the current one
+  assert!(page.contains(r##"href="#file-default__symbol_Foo""##));
+  assert!(!page.contains("__file-default__symbol_Foo"));
+}
+
+#[tokio::test]
+async fn destructured_param_names() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export function f(
+  { a, b }: { a: string; b: number },
+  { a: x }: { a: string },
+  { a: { b: [c] } }: any,
+  [g, , h]: string[],
+  { i, ...rest }: any,
+  ...[d, e]: string[]
+) {}
+"#,
+  )
+  .await;
+
+  let page = files.get("./~/f.html").unwrap();
+  let name = |i: usize, name: &str| {
+    doc_entry(page, &format!("function_f_0_parameters_unnamed-{i}"))
+      .contains(&format!(r#"<span class="font-bold font-lg">{name}</span>"#))
+  };
+
+  assert!(name(0, "{ a, b }"));
+  assert!(name(1, "{ a: x }"));
+  assert!(name(2, "{ a: { b: [c] } }"));
+  assert!(name(3, "[g, , h]"));
+  assert!(name(4, "{ i, ...rest }"));
+  assert!(
+    doc_entry(page, "function_f_0_parameters____unnamed-5").contains(
+      r#"<span class="font-bold font-lg"><span>...[d, e]</span></span>"#
+    )
+  );
+}
+
+#[tokio::test]
+async fn constructor_param_modifiers() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+class Base { y: unknown; }
+export class Foo extends Base {
+  constructor(private readonly x: number, public override y) { super(); }
+}
+"#,
+  )
+  .await;
+
+  let constructor =
+    doc_entry(files.get("./~/Foo.html").unwrap(), "constructor_0");
+  assert!(constructor.contains(
+    "<span><span>private readonly </span><span>x<span>: <span>number</span></span></span></span>"
+  ));
+  assert!(constructor
+    .contains("<span><span>public override </span><span>y</span></span>"));
+}
+
+#[tokio::test]
+async fn optional_methods() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export interface Iface { bar?(): void; req(): void }
+export class Klass { baz?(): void {} }
+export type Lit = { qux?(): void };
+"#,
+  )
+  .await;
+
+  for symbol in ["Iface.bar", "Klass.prototype.baz", "Lit.qux"] {
+    let page = files.get(&format!("./~/{symbol}.html")).unwrap();
+    assert!(page.contains(">Optional</div>"), "{symbol}");
+  }
+  assert!(!files
+    .get("./~/Iface.req.html")
+    .unwrap()
+    .contains(">Optional</div>"));
+
+  let method = doc_entry(files.get("./~/Klass.html").unwrap(), "method_baz_0");
+  assert!(method.contains(">optional</div>"));
+  assert!(!method.contains(">abstract</div>"));
+}
+
+#[tokio::test]
+async fn sort_overloads() {
+  let source = r#"
+export function foo<T>(value: T): void;
+export function foo(value: string): void;
+export function foo(value: "a"): void;
+export function foo(value: unknown) {}
+"#;
+
+  let order = |page: &str| {
+    let mut ids = (0..3)
+      .map(|i| {
+        let id = format!(r#"id="function_foo_{i}""#);
+        (page.find(&id).unwrap(), i)
+      })
+      .collect::<Vec<_>>();
+    ids.sort();
+    ids.into_iter().map(|(_, i)| i).collect::<Vec<_>>()
+  };
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), source)
+      .await;
+  assert_eq!(order(files.get("./~/foo.html").unwrap()), [0, 1, 2]);
+
+  let files = generate_source(
+    GenerateOptions {
+      sort_overloads: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    source,
+  )
+  .await;
+  assert_eq!(order(files.get("./~/foo.html").unwrap()), [2, 1, 0]);
+}
+
+#[tokio::test]
+async fn examples_position() {
+  let source = r#"
+/**
+ * Does foo.
+ *
+ * @example Basic
+ * ```ts
+ * foo(1);
+ * ```
+ */
+export function foo(a: number) {}
+"#;
+
+  let positions = |examples_position| async move {
+    let files = generate_source(
+      GenerateOptions {
+        examples_position,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      source,
+    )
+    .await;
+    let page = files.get("./~/foo.html").unwrap();
+    let main = &page[page.find("<main").unwrap()..];
+
+    ["Does foo.", r#"id="examples""#, r#"id="return-type""#]
+      .map(|needle| main.find(needle).unwrap())
+  };
+
+  let [docs, examples, return_type] =
+    positions(ExamplesPosition::AfterDocs).await;
+  assert!(docs < examples && examples < return_type);
+
+  let [docs, examples, _] = positions(ExamplesPosition::BeforeDocs).await;
+  assert!(examples < docs);
+
+  let [docs, examples, return_type] = positions(ExamplesPosition::End).await;
+  assert!(docs < return_type && return_type < examples);
+}
+
+#[tokio::test]
+async fn link_summary_return_type() {
+  let summary = |link_summary_return_type| async move {
+    let files = generate_source(
+      GenerateOptions {
+        link_summary_return_type,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      "export function isString(value: unknown): value is string { return true; }",
+    )
+    .await;
+    let page = files.get("./~/isString.html").unwrap();
+
+    page[..page.find(r#"id="return-type""#).unwrap()].to_string()
+  };
+  let linked = r##"<a href="#function_isString_0_parameters_value" class="link">value</a> is"##;
+
+  assert!(!summary(false).await.contains(linked));
+  assert!(summary(true).await.contains(linked));
+}
+
+#[tokio::test]
+async fn compact_params() {
+  let source = r#"
+/**
+ * @param a The a.
+ */
+export function foo(a: number, b?: string) {}
+"#;
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), source)
+      .await;
+  let page = files.get("./~/foo.html").unwrap();
+  assert!(!page.contains(r#"<p class="compactParams">"#));
+  assert!(doc_entry(page, "function_foo_0_parameters_a").contains("The a."));
+
+  let files = generate_source(
+    GenerateOptions {
+      compact_params: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    source,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+  assert!(page.contains(
+    r#"<p class="compactParams"><span id="function_foo_0_parameters_a" title="a: number
+The a.">a</span>, <span id="function_foo_0_parameters_b" title="b?: string">b</span></p>"#
+  ));
+  assert!(!page.contains(r#"docEntry" id="function_foo_0_parameters_a""#));
+}
+
+#[tokio::test]
+async fn inline_first_example() {
+  let positions = |inline_first_example| async move {
+    let files = generate_source(
+      GenerateOptions {
+        inline_first_example,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      r#"
+/**
+ * Does foo.
+ *
+ * @example First
+ * ```ts
+ * foo(1);
+ * ```
+ *
+ * @example Second
+ * ```ts
+ * foo(2);
+ * ```
+ */
+export function foo(a: number) {}
+"#,
+    )
+    .await;
+    let page = files.get("./~/foo.html").unwrap();
+
+    [r#"id="example_0""#, r#"id="examples""#, r#"id="example_1""#]
+      .map(|needle| page.find(needle).unwrap())
+  };
+
+  let [first, examples, second] = positions(false).await;
+  assert!(examples < first && first < second);
+
+  // only the first example is moved out of the examples section
+  let [first, examples, second] = positions(true).await;
+  assert!(first < examples && examples < second);
+}
+
+#[tokio::test]
+async fn section_toc_threshold() {
+  let toc = |section_toc_threshold| async move {
+    let files = generate_source(
+      GenerateOptions {
+        section_toc_threshold,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      "export function foo(a: number): string { return ''; }",
+    )
+    .await;
+    let page = files.get("./~/foo.html").unwrap();
+
+    page
+      .find(r#"<nav class="sectionToc">"#)
+      .map(|start| page[start..].split_once("</nav>").unwrap().0.to_string())
+  };
+
+  assert_eq!(toc(None).await, None);
+  // there are only the Parameters and Return Type sections
+  assert_eq!(toc(Some(3)).await, None);
+  assert_eq!(
+    toc(Some(2)).await.unwrap(),
+    r##"<nav class="sectionToc"><a href="#parameters">Parameters</a><a href="#return-type">Return Type</a>"##
+  );
+}
+
+#[tokio::test]
+async fn enum_member_defaults() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export enum Color { Red, Green }
+export const obj = { Red: 1 };
+export function paint(color = Color.Red, other = obj.Red, missing = Color.Blue) {}
+"#,
+  )
+  .await;
+
+  let page = files.get("./~/paint.html").unwrap();
+  assert!(page.contains(
+    r#"= </span><a href="../././~/Color.html#enum_color_red" class="link">Color.Red</a></span>"#
+  ));
+  // only members of enums are linked
+  assert!(page.contains("= </span>[obj.Red]</span>"));
+  assert!(page.contains("= </span>[Color.Blue]</span>"));
+}
+
+#[tokio::test]
+async fn optional_param_undefined() {
+  let params = |optional_param_undefined| async move {
+    let files = generate_source(
+      GenerateOptions {
+        optional_param_undefined,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      r#"export function foo(a?: string, b: number = 1, c: string | undefined = "c") {}"#,
+    )
+    .await;
+    let page = files.get("./~/foo.html").unwrap();
+
+    ["a", "b", "c"].map(|name| {
+      doc_entry(page, &format!("function_foo_0_parameters_{name}")).to_string()
+    })
+  };
+
+  let undefined = "<span> | </span><span>undefined</span>";
+
+  let [a, b, c] = params(false).await;
+  assert!(!a.contains(undefined));
+  assert!(!b.contains(undefined));
+  assert_eq!(c.matches(undefined).count(), 1);
+
+  let [a, b, c] = params(true).await;
+  assert!(a.contains(undefined));
+  assert!(b.contains(undefined));
+  // `undefined` is not added a second time
+  assert_eq!(c.matches(undefined).count(), 1);
+}
+
+#[tokio::test]
+async fn awaited_return_type() {
+  let source = r#"
+export interface Foo {}
+export async function foo(): Promise<Foo> { return {}; }
+export function bar(): Foo { return {}; }
+"#;
+
+  let files =
+    generate_source(GenerateOptions::new(Rc::new(EmptyResolver {})), source)
+      .await;
+  assert!(!files.get("./~/foo.html").unwrap().contains("awaitedType"));
+
+  let files = generate_source(
+    GenerateOptions {
+      awaited_return_type: true,
+      ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+    },
+    source,
+  )
+  .await;
+  assert!(doc_entry(files.get("./~/foo.html").unwrap(), "function_foo_0_return")
+    .contains(
+      r#"<span class="awaitedType">resolves to <a href="../././~/Foo.html" class="link">Foo</a></span>"#
+    ));
+  assert!(!files.get("./~/bar.html").unwrap().contains("awaitedType"));
+}
+
+#[tokio::test]
+async fn chainable_methods() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+export class Builder {
+  add(): this { return this; }
+  build(): string { return ""; }
+}
+"#,
+  )
+  .await;
+
+  assert!(doc_entry(
+    files.get("./~/Builder.prototype.add.html").unwrap(),
+    "function_Builder_prototype_add_0_return"
+  )
+  .contains(">chainable</div>"));
+  assert!(!files
+    .get("./~/Builder.prototype.build.html")
+    .unwrap()
+    .contains(">chainable</div>"));
+}
+
+#[tokio::test]
+async fn import_specifier() {
+  let usage = |import_specifier| async move {
+    let files = generate_source(
+      GenerateOptions {
+        import_specifier,
+        ..GenerateOptions::new(Rc::new(EmptyResolver {}))
+      },
+      "export function foo() {}",
+    )
+    .await;
+    let page = files.get("./~/foo.html").unwrap();
+    let start = page.find("data-copy=\"").unwrap();
+
+    page[start..].split_once("\n\"").unwrap().0.to_string()
+  };
+
+  assert_eq!(
+    usage(None).await,
+    "data-copy=\"import { foo } from &quot;.&quot;;"
+  );
+  assert_eq!(
+    usage(Some("jsr:@std/fs".to_string())).await,
+    "data-copy=\"import { foo } from &quot;jsr:@std/fs&quot;;"
+  );
+}
+
+#[tokio::test]
+async fn overload_order_tag_name() {
+  let files = generate_source(
+    GenerateOptions::new(Rc::new(EmptyResolver {})),
+    r#"
+/** @overloadOrder2 */
+export function foo(a: boolean): string;
+/** @overloadOrder1 */
+export function foo(a: string): string;
+export function foo(a: unknown): unknown {}
+"#,
+  )
+  .await;
+  let page = files.get("./~/foo.html").unwrap();
+
+  // `@overloadOrder1` is a different tag, so the source order is kept
+  assert!(
+    page.find(r#"id="function_foo_0""#).unwrap()
+      < page.find(r#"id="function_foo_1""#).unwrap()
+  );
+}